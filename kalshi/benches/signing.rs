@@ -0,0 +1,49 @@
+//! Compares the per-request cost of signing with a key parsed once per client against
+//! parsing the PEM for every request, as `auth_headers` used to.
+//!
+//! Run with `cargo bench --bench signing`.
+
+use kalshi::RequestSigner;
+use openssl::rsa::Rsa;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: u32 = 200;
+const MESSAGE: &[u8] = b"1700000000000GET/trade-api/v2/portfolio/orders";
+
+fn time_per_request(mut f: impl FnMut()) -> Duration {
+    // Warm up allocators and OpenSSL's lazy initialisation.
+    for _ in 0..10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let key = Rsa::generate(2048).expect("generate RSA key");
+    let pem = String::from_utf8(key.private_key_to_pem().expect("encode key")).unwrap();
+
+    let parse_each_time = time_per_request(|| {
+        let signer = RequestSigner::from_pem(black_box(&pem)).unwrap();
+        black_box(signer.sign(MESSAGE).unwrap());
+    });
+
+    let signer = RequestSigner::from_pem(&pem).unwrap();
+    let cached = time_per_request(|| {
+        black_box(signer.sign(black_box(MESSAGE)).unwrap());
+    });
+
+    println!("parse PEM and sign per request: {:?}", parse_each_time);
+    println!("sign with the cached key:       {:?}", cached);
+    println!(
+        "saved per request:              {:?} ({:.1}x faster)",
+        parse_each_time.saturating_sub(cached),
+        parse_each_time.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -15,7 +15,10 @@ impl Kalshi {
     /// Creates a new API key using a provided RSA public key.
     ///
     /// Maps to POST /api_keys
-    pub async fn create_api_key(&self, payload: CreateApiKeyRequest) -> Result<String, KalshiError> {
+    pub async fn create_api_key(
+        &self,
+        payload: CreateApiKeyRequest,
+    ) -> Result<String, KalshiError> {
         let url = self.build_url("/api_keys")?;
         let resp: CreateApiKeyResponse = self.http_post(url, &payload).await?;
         Ok(resp.api_key_id)
@@ -25,7 +28,10 @@ impl Kalshi {
     /// The private key cannot be retrieved again after this response.
     ///
     /// Maps to POST /api_keys/generate
-    pub async fn generate_api_key(&self, payload: GenerateApiKeyRequest) -> Result<GenerateApiKeyResponse, KalshiError> {
+    pub async fn generate_api_key(
+        &self,
+        payload: GenerateApiKeyRequest,
+    ) -> Result<GenerateApiKeyResponse, KalshiError> {
         let url = self.build_url("/api_keys/generate")?;
         self.http_post(url, &payload).await
     }
@@ -85,4 +91,4 @@ pub struct GenerateApiKeyRequest {
 pub struct GenerateApiKeyResponse {
     pub api_key_id: String,
     pub private_key: String,
-}
\ No newline at end of file
+}
@@ -0,0 +1,172 @@
+use super::Kalshi;
+use crate::kalshi_error::*;
+use crate::{Event, EventMetadata, ExchangeSchedule, Series};
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+// METADATA CACHE
+// -----------------------------------------------
+
+impl Kalshi {
+    /// Enables the in-memory metadata cache with the given time-to-live settings.
+    ///
+    /// Once enabled, [`get_event`](Kalshi::get_event) (without nested markets),
+    /// [`get_event_metadata`](Kalshi::get_event_metadata), [`get_series`](Kalshi::get_series)
+    /// and [`get_exchange_schedule`](Kalshi::get_exchange_schedule) serve repeated calls
+    /// from memory until the entry expires. Clones of this client share the same cache.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{CacheConfig, Kalshi};
+    /// # use std::time::Duration;
+    /// # fn example(k: Kalshi) -> Kalshi {
+    /// k.with_cache(CacheConfig {
+    ///     series_ttl: Duration::from_secs(6 * 60 * 60),
+    ///     ..Default::default()
+    /// })
+    /// # }
+    /// ```
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(Arc::new(MetadataCache::new(config)));
+        self
+    }
+
+    /// Removes a cached event and its metadata, so the next request fetches them again.
+    pub fn invalidate_event(&self, event_ticker: &str) {
+        if let Some(cache) = &self.cache {
+            cache.events.remove(event_ticker);
+            cache.event_metadata.remove(event_ticker);
+        }
+    }
+
+    /// Removes a cached series, so the next request fetches it again.
+    pub fn invalidate_series(&self, series_ticker: &str) {
+        if let Some(cache) = &self.cache {
+            cache.series.remove(series_ticker);
+        }
+    }
+
+    /// Removes the cached exchange schedule, so the next request fetches it again.
+    pub fn invalidate_exchange_schedule(&self) {
+        if let Some(cache) = &self.cache {
+            cache.exchange_schedule.remove(&());
+        }
+    }
+
+    /// Removes every cached entry.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.events.clear();
+            cache.event_metadata.clear();
+            cache.series.clear();
+            cache.exchange_schedule.clear();
+        }
+    }
+}
+
+/// Time-to-live settings for the metadata cache enabled by [`Kalshi::with_cache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// How long events and event metadata stay cached. Defaults to 5 minutes.
+    pub event_ttl: Duration,
+    /// How long series stay cached. Defaults to 1 hour.
+    pub series_ttl: Duration,
+    /// How long the exchange schedule stays cached. Defaults to 5 minutes.
+    pub exchange_schedule_ttl: Duration,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            event_ttl: Duration::from_secs(5 * 60),
+            series_ttl: Duration::from_secs(60 * 60),
+            exchange_schedule_ttl: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MetadataCache {
+    pub(crate) events: TtlMap<String, Event>,
+    pub(crate) event_metadata: TtlMap<String, EventMetadata>,
+    pub(crate) series: TtlMap<String, Series>,
+    pub(crate) exchange_schedule: TtlMap<(), ExchangeSchedule>,
+}
+
+impl MetadataCache {
+    fn new(config: CacheConfig) -> Self {
+        MetadataCache {
+            events: TtlMap::new(config.event_ttl),
+            event_metadata: TtlMap::new(config.event_ttl),
+            series: TtlMap::new(config.series_ttl),
+            exchange_schedule: TtlMap::new(config.exchange_schedule_ttl),
+        }
+    }
+}
+
+/// A map whose entries expire a fixed time after they were inserted.
+#[derive(Debug)]
+pub(crate) struct TtlMap<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlMap<K, V> {
+    fn new(ttl: Duration) -> Self {
+        TtlMap {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((inserted, value)) if inserted.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn remove<Q>(&self, key: &Q)
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Returns the cached value for `key`, or awaits `fetch` and caches its result.
+    ///
+    /// Errors are returned as-is and never cached.
+    pub(crate) async fn get_or_fetch<Fut>(&self, key: K, fetch: Fut) -> Result<V, KalshiError>
+    where
+        Fut: Future<Output = Result<V, KalshiError>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        let value = fetch.await?;
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), value.clone()));
+        Ok(value)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{transport::HttpResponse, Kalshi, KalshiError};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{sync::watch, time::Instant};
+use tracing::warn;
+
+/// State of the circuit breaker set with [`Kalshi::with_circuit_breaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Requests are sent normally.
+    Closed,
+    /// Requests fail fast with [`KalshiError::CircuitOpen`] until the cool-down ends.
+    Open,
+    /// The cool-down has ended and a single probe request is let through; its outcome
+    /// closes or reopens the circuit.
+    HalfOpen,
+}
+
+/// When the circuit breaker opens and for how long, see
+/// [`Kalshi::with_circuit_breaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive server errors or timeouts that open the circuit. Defaults to 5.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a probe request is let through. Defaults
+    /// to 30 seconds.
+    pub cool_down: Duration,
+}
+
+impl CircuitBreakerConfig {
+    /// Opens the circuit after `failure_threshold` consecutive failures, for `cool_down`.
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        CircuitBreakerConfig {
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+        }
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        CircuitBreakerConfig::new(5, Duration::from_secs(30))
+    }
+}
+
+#[derive(Default)]
+struct BreakerState {
+    failures: u32,
+    opened_at: Option<Instant>,
+    /// When the probe of a half-open circuit was let through.
+    probe_started: Option<Instant>,
+}
+
+/// Tracks consecutive failures and fails requests fast while the circuit is open.
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+    events: watch::Sender<CircuitState>,
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        CircuitBreaker {
+            config,
+            state: Mutex::new(BreakerState::default()),
+            events: watch::channel(CircuitState::Closed).0,
+        }
+    }
+
+    /// Returns an error if the request must not be sent.
+    pub(crate) fn check(&self) -> Result<(), KalshiError> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        match *self.events.borrow() {
+            CircuitState::Closed => return Ok(()),
+            CircuitState::HalfOpen => {
+                // A probe that was dropped before finishing must not keep the circuit
+                // half-open forever.
+                let stale = state
+                    .probe_started
+                    .map_or(true, |started| now - started >= self.config.cool_down);
+                if stale {
+                    state.probe_started = Some(now);
+                    return Ok(());
+                }
+            }
+            CircuitState::Open => {
+                let opened_at = state.opened_at.unwrap_or(now);
+                if now - opened_at >= self.config.cool_down {
+                    state.probe_started = Some(now);
+                    self.transition(CircuitState::HalfOpen);
+                    return Ok(());
+                }
+                let remaining = self.config.cool_down - (now - opened_at);
+                return Err(KalshiError::CircuitOpen(format!(
+                    "failing fast after {} consecutive failures, retrying in {:?}",
+                    state.failures, remaining
+                )));
+            }
+        }
+        Err(KalshiError::CircuitOpen(
+            "waiting for the probe request of a half-open circuit".to_string(),
+        ))
+    }
+
+    /// Records the outcome of a request that was sent.
+    pub(crate) fn record(&self, res: &Result<HttpResponse, KalshiError>) {
+        let failed = match res {
+            Ok(resp) => resp.status.is_server_error(),
+            Err(e) => e.is_retryable(),
+        };
+        let mut state = self.state.lock().unwrap();
+        let current = *self.events.borrow();
+        if !failed {
+            state.failures = 0;
+            state.probe_started = None;
+            if current != CircuitState::Closed {
+                self.transition(CircuitState::Closed);
+            }
+            return;
+        }
+        state.failures += 1;
+        let reopen = current == CircuitState::HalfOpen;
+        let trip =
+            current == CircuitState::Closed && state.failures >= self.config.failure_threshold;
+        if reopen || trip {
+            state.opened_at = Some(Instant::now());
+            state.probe_started = None;
+            self.transition(CircuitState::Open);
+        }
+    }
+
+    fn transition(&self, to: CircuitState) {
+        warn!("Circuit breaker is now {:?}", to);
+        self.events.send_replace(to);
+    }
+}
+
+impl Kalshi {
+    /// Stops sending REST requests for a while after repeated failures.
+    ///
+    /// After `config.failure_threshold` consecutive 5xx responses, timeouts or
+    /// connection errors the circuit opens: every request fails immediately with
+    /// [`KalshiError::CircuitOpen`] for `config.cool_down`, sparing both the exchange and
+    /// the bot during an outage. Then one probe request is let through, and its outcome
+    /// closes the circuit or opens it for another cool-down. Clones of this client share
+    /// the breaker. Not available on wasm32.
+    pub fn with_circuit_breaker(mut self, config: Option<CircuitBreakerConfig>) -> Self {
+        self.circuit_breaker = config.map(|config| Arc::new(CircuitBreaker::new(config)));
+        self
+    }
+
+    /// Returns a receiver that is updated whenever the circuit breaker changes state,
+    /// or `None` if no circuit breaker is set.
+    pub fn circuit_state(&self) -> Option<watch::Receiver<CircuitState>> {
+        self.circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.events.subscribe())
+    }
+}
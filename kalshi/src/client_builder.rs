@@ -0,0 +1,354 @@
+use crate::{
+    kalshi_error::*, HttpTransport, Kalshi, KalshiAuth, ProxyConfig, RateLimits, RetryPolicy,
+    TradingEnvironment,
+};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::{sync::Arc, time::Duration};
+
+/// Timeout for a whole REST request used by [`KalshiBuilder`] unless overridden.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Timeout for opening a REST connection used by [`KalshiBuilder`] unless overridden.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The Kalshi deployment a [`Kalshi`] client talks to.
+///
+/// Each environment sets both the REST base URL and the websocket URL, so the two can
+/// never point at different deployments by accident.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Environment {
+    /// The live exchange, trading with real money.
+    Prod,
+    /// The demo exchange, where trades do not involve real money.
+    Demo,
+    /// Any other deployment, given by its origin, e.g. `https://kalshi-proxy.internal`.
+    ///
+    /// The REST API is expected under `/trade-api/v2` and the websocket under
+    /// `/trade-api/ws/v2`, as on Kalshi's own hosts.
+    Custom(String),
+}
+
+impl Environment {
+    /// Returns the REST base URL of the environment.
+    pub fn rest_url(&self) -> String {
+        match self {
+            Environment::Prod => {
+                crate::utils::build_base_url(TradingEnvironment::LiveMarketMode).to_string()
+            }
+            Environment::Demo => {
+                crate::utils::build_base_url(TradingEnvironment::DemoMode).to_string()
+            }
+            Environment::Custom(origin) => format!("{}/trade-api/v2", origin.trim_end_matches('/')),
+        }
+    }
+
+    /// Returns the websocket URL of the environment.
+    pub fn ws_url(&self) -> String {
+        match self {
+            Environment::Prod => {
+                crate::utils::build_ws_url(TradingEnvironment::LiveMarketMode).to_string()
+            }
+            Environment::Demo => {
+                crate::utils::build_ws_url(TradingEnvironment::DemoMode).to_string()
+            }
+            Environment::Custom(origin) => {
+                let origin = origin.trim_end_matches('/');
+                let origin = match origin.split_once("://") {
+                    Some(("http", host)) => format!("ws://{}", host),
+                    Some(("https", host)) => format!("wss://{}", host),
+                    _ => origin.to_string(),
+                };
+                format!("{}/trade-api/ws/v2", origin)
+            }
+        }
+    }
+}
+
+/// Builder for a [`Kalshi`] client, see [`Kalshi::builder`].
+///
+/// On `wasm32` the browser's fetch API sends the requests, so timeout, pool, keepalive,
+/// HTTP/2 and proxy settings have no effect there.
+pub struct KalshiBuilder {
+    environment: Environment,
+    rest_url: Option<String>,
+    ws_url: Option<String>,
+    auth: Option<KalshiAuth>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    proxy: Option<ProxyConfig>,
+    headers: Vec<(String, String)>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    tcp_keepalive: Option<Duration>,
+    http2_only: bool,
+    http2_keepalive: Option<(Duration, Duration)>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limits: Option<RateLimits>,
+}
+
+impl Default for KalshiBuilder {
+    fn default() -> Self {
+        KalshiBuilder {
+            environment: Environment::Demo,
+            rest_url: None,
+            ws_url: None,
+            auth: None,
+            transport: None,
+            proxy: None,
+            headers: Vec::new(),
+            timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+            connect_timeout: Some(DEFAULT_CONNECT_TIMEOUT),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http2_only: false,
+            http2_keepalive: None,
+            retry_policy: None,
+            rate_limits: None,
+        }
+    }
+}
+
+impl KalshiBuilder {
+    /// Sets the environment to connect to. Defaults to [`Environment::Demo`], so going
+    /// live is always an explicit choice.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Overrides the REST base URL derived from the environment.
+    pub fn rest_url(mut self, url: impl Into<String>) -> Self {
+        self.rest_url = Some(url.into());
+        self
+    }
+
+    /// Overrides the websocket URL derived from the environment.
+    pub fn ws_url(mut self, url: impl Into<String>) -> Self {
+        self.ws_url = Some(url.into());
+        self
+    }
+
+    /// Sets the credentials. If none are given, [`build`](KalshiBuilder::build) loads
+    /// them with [`KalshiAuth::from_env`].
+    pub fn auth(mut self, auth: KalshiAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Sends requests through a preconfigured HTTP client, see
+    /// [`Kalshi::with_http_client`]. The client's own timeout, pool and proxy settings
+    /// apply instead of the ones set on this builder.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.transport = Some(Arc::new(client));
+        self
+    }
+
+    /// Sends requests through a client with a `reqwest-middleware` stack, see
+    /// [`Kalshi::with_middleware_client`]. The client's own timeout, pool and proxy
+    /// settings apply instead of the ones set on this builder.
+    #[cfg(feature = "middleware")]
+    pub fn middleware_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.transport = Some(Arc::new(client));
+        self
+    }
+
+    /// Sends requests through a custom [`HttpTransport`], see [`Kalshi::with_transport`].
+    /// Timeout, pool and proxy settings of this builder do not apply.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the `User-Agent` sent with every REST request and websocket handshake, e.g.
+    /// to identify an integration to Kalshi support.
+    pub fn user_agent(self, user_agent: impl Into<String>) -> Self {
+        self.default_header("user-agent", user_agent)
+    }
+
+    /// Adds a header sent with every REST request and websocket handshake, e.g. to tag
+    /// the traffic of one tenant. Authentication headers cannot be overridden.
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sends REST requests and opens websocket connections through a proxy.
+    ///
+    /// Does not apply to a client or transport set with
+    /// [`http_client`](KalshiBuilder::http_client) or
+    /// [`transport`](KalshiBuilder::transport); configure the proxy on those directly.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the timeout for a whole REST request, or `None` to wait indefinitely.
+    /// Defaults to [`DEFAULT_REQUEST_TIMEOUT`].
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the timeout for opening a REST connection, or `None` to wait indefinitely.
+    /// Defaults to [`DEFAULT_CONNECT_TIMEOUT`].
+    pub fn connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets how many idle connections per host the REST client keeps open. Defaults to
+    /// no limit.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long an idle REST connection stays in the pool, or `None` to keep it
+    /// until the server closes it. Defaults to 90 seconds.
+    ///
+    /// Order flow that pauses between bursts benefits from keeping connections warm.
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables TCP keepalive probes on REST connections at the given interval, so
+    /// middleboxes do not silently drop idle pooled connections. Disabled by default.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Speaks HTTP/2 from the first byte instead of negotiating it. Only use this with
+    /// servers known to support HTTP/2; otherwise HTTP/2 is still picked automatically
+    /// during the TLS handshake when the server offers it.
+    pub fn http2_only(mut self, enabled: bool) -> Self {
+        self.http2_only = enabled;
+        self
+    }
+
+    /// Sends HTTP/2 keepalive pings every `interval`, also while no request is in
+    /// flight, and drops the connection if a ping is not answered within `timeout`.
+    /// Keeps the connection warm between bursts of orders and detects dead ones early.
+    pub fn http2_keepalive(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.http2_keepalive = Some((interval, timeout));
+        self
+    }
+
+    /// Retries idempotent requests that failed transiently, see [`RetryPolicy`].
+    /// Disabled by default.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Paces requests to stay within `limits`, e.g. the limits of a
+    /// [`RateLimitTier`](crate::RateLimitTier). Disabled by default.
+    pub fn rate_limits(mut self, limits: impl Into<RateLimits>) -> Self {
+        self.rate_limits = Some(limits.into());
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Returns
+    /// - `Ok(Kalshi)`: The configured client.
+    /// - `Err(KalshiError)`: If no credentials were given and none could be loaded from
+    ///   the environment, a default header is invalid, or the HTTP client could not be
+    ///   created.
+    pub fn build(self) -> Result<Kalshi, KalshiError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let invalid = |e: &dyn std::fmt::Display| {
+                KalshiError::UserInputError(format!("Invalid header {}: {}", name, e))
+            };
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| invalid(&e))?,
+                HeaderValue::from_str(value).map_err(|e| invalid(&e))?,
+            );
+        }
+        let transport = match self.transport.clone() {
+            Some(transport) => transport,
+            None => Arc::new(self.build_reqwest()?),
+        };
+        let auth = match self.auth {
+            Some(auth) => auth,
+            None => KalshiAuth::from_env()?,
+        };
+
+        let mut kalshi = Kalshi::with_auth(TradingEnvironment::DemoMode, auth)
+            .with_default_headers(headers)
+            .with_retry_policy(self.retry_policy)
+            .with_rate_limits(self.rate_limits);
+        kalshi.transport = transport;
+        kalshi.base_url = self.rest_url.unwrap_or_else(|| self.environment.rest_url());
+        #[cfg(feature = "websockets")]
+        {
+            kalshi.ws_url = self.ws_url.unwrap_or_else(|| self.environment.ws_url());
+            kalshi.ws_connector.proxy = self.proxy;
+        }
+        Ok(kalshi)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_reqwest(&self) -> Result<reqwest::Client, KalshiError> {
+        let mut client = crate::http::client_builder();
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            client = client.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            client = client.tcp_keepalive(interval);
+        }
+        if self.http2_only {
+            client = client.http2_prior_knowledge();
+        }
+        if let Some((interval, timeout)) = self.http2_keepalive {
+            client = client
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_timeout(timeout)
+                .http2_keep_alive_while_idle(true);
+        }
+        if let Some(proxy) = &self.proxy {
+            client = client.proxy(proxy.to_reqwest()?);
+        }
+        Ok(client.build()?)
+    }
+
+    // The browser owns timeouts, pooling and proxies on wasm32, so none of them apply.
+    #[cfg(target_arch = "wasm32")]
+    fn build_reqwest(&self) -> Result<reqwest::Client, KalshiError> {
+        Ok(crate::http::client_builder().build()?)
+    }
+}
+
+impl Kalshi {
+    /// Returns a builder that configures the environment, credentials and timeouts of a
+    /// new client.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{Environment, Kalshi, KalshiAuth};
+    /// # use std::time::Duration;
+    /// # fn example() -> Result<Kalshi, kalshi::KalshiError> {
+    /// let kalshi = Kalshi::builder()
+    ///     .environment(Environment::Prod)
+    ///     .auth(KalshiAuth::from_pem_file("your-api-key-id", "kalshi.pem")?)
+    ///     .timeout(Some(Duration::from_secs(10)))
+    ///     .build()?;
+    /// # Ok(kalshi)
+    /// # }
+    /// ```
+    pub fn builder() -> KalshiBuilder {
+        KalshiBuilder::default()
+    }
+}
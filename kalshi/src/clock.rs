@@ -0,0 +1,99 @@
+use crate::{kalshi_error::*, Kalshi};
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::{header::DATE, Method};
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::{task::JoinHandle, time::interval};
+use tracing::{debug, warn};
+
+/// Clock offsets larger than this are logged as a warning by [`Kalshi::sync_clock`].
+pub const CLOCK_DRIFT_WARNING: Duration = Duration::from_secs(2);
+
+/// Exchange clock minus local clock in milliseconds, shared between clones of a client.
+pub(crate) type ClockOffset = Arc<AtomicI64>;
+
+impl Kalshi {
+    /// Measures the offset between the local clock and the exchange and applies it to
+    /// the timestamps of signed requests.
+    ///
+    /// Kalshi rejects signatures whose timestamp is too far from its own clock, so hosts
+    /// with a drifting clock start failing every request. The offset is estimated from
+    /// the `Date` header of an exchange status call, taken at the midpoint of the round
+    /// trip, and is accurate to about a second. It is shared by all clones of this
+    /// client. Offsets above [`CLOCK_DRIFT_WARNING`] are logged as warnings.
+    ///
+    /// # Returns
+    /// - `Ok(TimeDelta)`: The exchange clock minus the local clock.
+    /// - `Err(KalshiError)`: If the request failed or the response had no usable `Date`.
+    pub async fn sync_clock(&self) -> Result<TimeDelta, KalshiError> {
+        let url = self.build_url("/exchange/status")?;
+        let sent = Utc::now();
+        let resp = self.send(Method::GET, &url, None).await?;
+        let received = Utc::now();
+
+        let date = resp
+            .headers
+            .get(DATE)
+            .and_then(|date| date.to_str().ok())
+            .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+            .ok_or_else(|| {
+                KalshiError::InternalError("Exchange response has no valid Date header".to_string())
+            })?;
+        // The Date header is truncated to whole seconds, so on average it lags by half a second.
+        let server = date.with_timezone(&Utc) + TimeDelta::milliseconds(500);
+        let midpoint = sent + (received - sent) / 2;
+        let offset = server - midpoint;
+
+        self.clock_offset
+            .store(offset.num_milliseconds(), Ordering::Relaxed);
+        if offset.abs().to_std().unwrap_or_default() > CLOCK_DRIFT_WARNING {
+            warn!(
+                "Local clock is {} ms {} the exchange; correcting signature timestamps",
+                offset.num_milliseconds().abs(),
+                if offset > TimeDelta::zero() {
+                    "behind"
+                } else {
+                    "ahead of"
+                }
+            );
+        } else {
+            debug!(
+                "Local clock offset to the exchange is {} ms",
+                offset.num_milliseconds()
+            );
+        }
+        Ok(offset)
+    }
+
+    /// Returns the offset applied to signature timestamps, as measured by the last
+    /// [`sync_clock`](Kalshi::sync_clock). Zero until the clock has been synchronized.
+    pub fn clock_offset(&self) -> TimeDelta {
+        TimeDelta::milliseconds(self.clock_offset_ms())
+    }
+
+    /// Spawns a task that calls [`sync_clock`](Kalshi::sync_clock) every `period`,
+    /// starting immediately. Failures are logged and retried at the next tick.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_clock_sync(&self, period: Duration) -> JoinHandle<()> {
+        let kalshi = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = kalshi.sync_clock().await {
+                    warn!("Clock synchronization failed: {}", e);
+                }
+            }
+        })
+    }
+
+    pub(crate) fn clock_offset_ms(&self) -> i64 {
+        self.clock_offset.load(Ordering::Relaxed)
+    }
+}
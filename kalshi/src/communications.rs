@@ -117,7 +117,11 @@ impl Kalshi {
     /// Accepts a quote.
     ///
     /// Maps to PUT /communications/quotes/{quote_id}/accept
-    pub async fn accept_quote(&self, quote_id: &str, payload: AcceptQuoteRequest) -> Result<(), KalshiError> {
+    pub async fn accept_quote(
+        &self,
+        quote_id: &str,
+        payload: AcceptQuoteRequest,
+    ) -> Result<(), KalshiError> {
         let path = format!("/communications/quotes/{}/accept", quote_id);
         let url = self.build_url(&path)?;
         self.http_put(url, &payload).await
@@ -237,4 +241,4 @@ pub struct CreateQuoteRequest {
 #[derive(Debug, Serialize, Clone)]
 pub struct AcceptQuoteRequest {
     pub accepted_side: String,
-}
\ No newline at end of file
+}
@@ -0,0 +1,166 @@
+use crate::kalshi_error::*;
+use serde::de::DeserializeOwned;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tokio::{sync::watch, task::JoinHandle, time::interval};
+use tracing::{info, warn};
+
+// RUNTIME CONFIGURATION
+// -----------------------------------------------
+
+/// Validation hook for configuration that can be reloaded at runtime.
+///
+/// A reload is only applied when `validate` returns `Ok`, so a typo in a watched
+/// file never replaces a working configuration with a broken one.
+pub trait Validate {
+    /// Checks that the configuration is internally consistent.
+    fn validate(&self) -> Result<(), KalshiError>;
+}
+
+/// A handle to configuration that can be swapped while the client is running.
+///
+/// Readers take cheap snapshots with [`current`](Reloadable::current) and always see
+/// either the old or the new value in full, never a partially updated one.
+/// Components that need to react to a change can [`subscribe`](Reloadable::subscribe)
+/// and await the next swap.
+///
+/// Cloning the handle is cheap; all clones share the same underlying value.
+///
+/// [`RateLimits`](crate::RateLimits) implement [`Validate`] and can drive a client's
+/// request pacing through
+/// [`Kalshi::with_reloadable_rate_limits`](crate::Kalshi::with_reloadable_rate_limits).
+/// With the `websockets` feature, exposure limits and the markets of a subscription
+/// can be reloaded the same way.
+///
+/// # Example
+/// ```
+/// # use kalshi::{Reloadable, Validate, KalshiError};
+/// # use std::time::Duration;
+/// #[derive(serde::Deserialize)]
+/// struct Limits {
+///     max_contracts: i64,
+/// }
+///
+/// impl Validate for Limits {
+///     fn validate(&self) -> Result<(), KalshiError> {
+///         if self.max_contracts <= 0 {
+///             return Err(KalshiError::UserInputError("max_contracts must be positive".into()));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// # async fn example() -> Result<(), KalshiError> {
+/// let limits = Reloadable::from_file("limits.json").await?;
+/// let _watcher = limits.watch_file("limits.json", Duration::from_secs(5));
+/// let snapshot: std::sync::Arc<Limits> = limits.current();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Reloadable<T> {
+    tx: Arc<watch::Sender<Arc<T>>>,
+}
+
+impl<T> Clone for Reloadable<T> {
+    fn clone(&self) -> Self {
+        Reloadable {
+            tx: Arc::clone(&self.tx),
+        }
+    }
+}
+
+impl<T: Validate + Send + Sync + 'static> Reloadable<T> {
+    /// Creates a new handle from an initial configuration.
+    ///
+    /// # Returns
+    /// - `Ok(Reloadable<T>)`: The handle, if the initial value passes validation.
+    /// - `Err(KalshiError)`: The validation error otherwise.
+    pub fn new(initial: T) -> Result<Self, KalshiError> {
+        initial.validate()?;
+        let (tx, _) = watch::channel(Arc::new(initial));
+        Ok(Reloadable { tx: Arc::new(tx) })
+    }
+
+    /// Returns a snapshot of the currently active configuration.
+    pub fn current(&self) -> Arc<T> {
+        Arc::clone(&self.tx.borrow())
+    }
+
+    /// Validates `new` and, if it passes, atomically replaces the active configuration.
+    ///
+    /// On validation failure the previous configuration stays in effect.
+    pub fn reload(&self, new: T) -> Result<(), KalshiError> {
+        new.validate()?;
+        self.tx.send_replace(Arc::new(new));
+        Ok(())
+    }
+
+    /// Returns a receiver that is notified every time the configuration is swapped.
+    pub fn subscribe(&self) -> watch::Receiver<Arc<T>> {
+        self.tx.subscribe()
+    }
+}
+
+impl<T: Validate + DeserializeOwned + Send + Sync + 'static> Reloadable<T> {
+    /// Creates a new handle by reading and validating a JSON file.
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self, KalshiError> {
+        Self::new(read_config_file(path.as_ref()).await?)
+    }
+
+    /// Re-reads a JSON file and applies it through [`reload`](Reloadable::reload).
+    pub async fn reload_from_file(&self, path: impl AsRef<Path>) -> Result<(), KalshiError> {
+        self.reload(read_config_file(path.as_ref()).await?)
+    }
+
+    /// Spawns a background task that polls `path` every `poll_interval` and reloads
+    /// the configuration whenever the file's modification time changes.
+    ///
+    /// Invalid or unreadable files are logged and skipped; the last good configuration
+    /// remains active. The task runs until the returned handle is aborted.
+    pub fn watch_file(&self, path: impl Into<PathBuf>, poll_interval: Duration) -> JoinHandle<()> {
+        let handle = self.clone();
+        let path = path.into();
+        tokio::spawn(async move {
+            let mut last_modified = modified_time(&path).await;
+            let mut ticker = interval(poll_interval);
+            loop {
+                ticker.tick().await;
+                let modified = modified_time(&path).await;
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+                last_modified = modified;
+                match handle.reload_from_file(&path).await {
+                    Ok(()) => info!("Reloaded configuration from {}", path.display()),
+                    Err(e) => warn!(
+                        "Rejected configuration reload from {}: {}",
+                        path.display(),
+                        e
+                    ),
+                }
+            }
+        })
+    }
+}
+
+async fn read_config_file<T: DeserializeOwned>(path: &Path) -> Result<T, KalshiError> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .map_err(|source| KalshiError::IoError {
+            context: format!("Unable to read configuration file {}", path.display()),
+            source,
+        })?;
+    crate::http::decode_json(&bytes).map_err(|(json_path, source)| KalshiError::JsonError {
+        context: format!("Unable to parse configuration file {}", path.display()),
+        path: json_path,
+        source,
+    })
+}
+
+async fn modified_time(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
@@ -0,0 +1,71 @@
+use crate::{kalshi_error::*, KalshiAuth};
+use keyring::Entry;
+use serde::{Deserialize, Serialize};
+
+// OS KEYRING
+// -----------------------------------------------
+
+/// What is kept in a keyring entry: the key id and the PEM private key.
+#[derive(Serialize, Deserialize)]
+struct StoredCredentials {
+    key_id: String,
+    private_key: String,
+}
+
+impl KalshiAuth {
+    /// Loads API key credentials saved with
+    /// [`store_in_keyring`](KalshiAuth::store_in_keyring) from the OS keyring.
+    ///
+    /// `service` and `user` name the entry, e.g. `"kalshi"` and the bot's name. The
+    /// private key is parsed like in [`api_key`](KalshiAuth::api_key), so it must not be
+    /// passphrase-protected.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{Kalshi, KalshiAuth, TradingEnvironment};
+    /// # fn example() -> Result<Kalshi, kalshi::KalshiError> {
+    /// let auth = KalshiAuth::from_keyring("kalshi", "market-maker")?;
+    /// let kalshi = Kalshi::with_auth(TradingEnvironment::DemoMode, auth);
+    /// # Ok(kalshi)
+    /// # }
+    /// ```
+    pub fn from_keyring(service: &str, user: &str) -> Result<Self, KalshiError> {
+        let secret = entry(service, user)?
+            .get_password()
+            .map_err(|e| keyring_error(service, user, e))?;
+        let stored: StoredCredentials =
+            serde_json::from_str(&secret).map_err(|source| KalshiError::JsonError {
+                context: format!("Unable to parse keyring entry {}/{}", service, user),
+                path: None,
+                source,
+            })?;
+        Self::api_key(stored.key_id, stored.private_key)
+    }
+
+    /// Saves these credentials in the OS keyring under `service` and `user`, replacing
+    /// any entry already there, so they can be loaded with
+    /// [`from_keyring`](KalshiAuth::from_keyring) instead of reading a PEM file.
+    pub fn store_in_keyring(&self, service: &str, user: &str) -> Result<(), KalshiError> {
+        let KalshiAuth::ApiKey { key_id, key, .. } = self;
+        let secret = serde_json::to_string(&StoredCredentials {
+            key_id: key_id.clone(),
+            private_key: key.clone(),
+        })
+        .map_err(|source| KalshiError::JsonError {
+            context: "Unable to serialize credentials for the keyring".to_string(),
+            path: None,
+            source,
+        })?;
+        entry(service, user)?
+            .set_password(&secret)
+            .map_err(|e| keyring_error(service, user, e))
+    }
+}
+
+fn entry(service: &str, user: &str) -> Result<Entry, KalshiError> {
+    Entry::new(service, user).map_err(|e| keyring_error(service, user, e))
+}
+
+fn keyring_error(service: &str, user: &str, e: keyring::Error) -> KalshiError {
+    KalshiError::AuthError(format!("Keyring entry {}/{}: {}", service, user, e))
+}
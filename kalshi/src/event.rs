@@ -1,9 +1,103 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
-use crate::SettlementSource;
+use crate::{paginate, Paginated, SettlementSource};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::debug;
 
 impl Kalshi {
+    /// Retrieves a page of events matching the given filters.
+    ///
+    /// Maps to GET /events
+    ///
+    /// Multivariate (combo) events are not included by this endpoint.
+    ///
+    /// # Arguments
+    /// * `params` - Filters and pagination for the request; use `Default::default()` for none.
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Event>, Option<String>))`: A page of events and the cursor for the next page, if any.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::{EventStatus, GetEventsParams};
+    ///
+    /// let (events, cursor) = k
+    ///     .get_events(&GetEventsParams {
+    ///         series_ticker: Some("KXHIGHNY".to_string()),
+    ///         status: Some(EventStatus::Open),
+    ///         with_nested_markets: true,
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_events(
+        &self,
+        params: &GetEventsParams,
+    ) -> Result<(Vec<crate::Event>, Option<String>), KalshiError> {
+        let mut query = Vec::new();
+        add_param!(query, "limit", params.limit);
+        add_param!(query, "cursor", params.cursor.as_ref());
+        add_param!(query, "series_ticker", params.series_ticker.as_ref());
+        add_param!(query, "status", params.status);
+        if params.with_nested_markets {
+            query.push(("with_nested_markets", true.to_string()));
+        }
+        add_param!(query, "min_close_ts", params.min_close_ts);
+        add_param!(query, "min_updated_ts", params.min_updated_ts);
+
+        let url = self.build_url_with_params("/events", query)?;
+        let resp: GetEventsResponse = self.http_get(url).await?;
+        let cursor = resp.cursor.filter(|c| !c.is_empty());
+        Ok((resp.events, cursor))
+    }
+
+    /// Streams every event matching `params`, fetching further pages as needed.
+    ///
+    /// Any `cursor` in `params` is used as the starting point; `limit` sets the page size.
+    pub fn stream_events(&self, params: GetEventsParams) -> Paginated<'_, crate::Event> {
+        paginate(params.cursor.clone(), move |cursor| {
+            let params = GetEventsParams {
+                cursor,
+                ..params.clone()
+            };
+            async move { self.get_events(&params).await }
+        })
+    }
+
+    /// Retrieves a single event by its ticker.
+    ///
+    /// Maps to GET /events/{event_ticker}
+    ///
+    /// # Arguments
+    /// * `event_ticker` - Ticker of the event.
+    /// * `with_nested_markets` - If true, the event's markets are returned in [`Event::markets`](crate::Event::markets);
+    ///   otherwise that field is `None`.
+    pub async fn get_event(
+        &self,
+        event_ticker: &str,
+        with_nested_markets: bool,
+    ) -> Result<crate::Event, KalshiError> {
+        #[cfg(feature = "cache")]
+        if let (Some(cache), false) = (&self.cache, with_nested_markets) {
+            let fetch = async { Ok(self.fetch_event(event_ticker, false).await?.event) };
+            return cache
+                .events
+                .get_or_fetch(event_ticker.to_string(), fetch)
+                .await;
+        }
+        let resp = self.fetch_event(event_ticker, with_nested_markets).await?;
+        let mut event = resp.event;
+        if !with_nested_markets {
+            event.markets = None;
+        }
+        Ok(event)
+    }
+
     /// Retrieves metadata for an event by its ticker.
     ///
     /// Maps to GET /events/{event_ticker}/metadata
@@ -11,6 +105,20 @@ impl Kalshi {
         &self,
         event_ticker: &str,
     ) -> Result<EventMetadata, KalshiError> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return cache
+                .event_metadata
+                .get_or_fetch(
+                    event_ticker.to_string(),
+                    self.fetch_event_metadata(event_ticker),
+                )
+                .await;
+        }
+        self.fetch_event_metadata(event_ticker).await
+    }
+
+    async fn fetch_event_metadata(&self, event_ticker: &str) -> Result<EventMetadata, KalshiError> {
         let path = format!("/events/{}/metadata", event_ticker);
         let url = self.build_url(&path)?;
         let result: EventMetadata = self.http_get(url).await?;
@@ -19,14 +127,43 @@ impl Kalshi {
 
     /// Retrieves aggregated candlestick data across all markets in an event.
     ///
-    /// Maps to GET /events/{event_ticker}/candlesticks
+    /// Maps to GET /series/{series_ticker}/events/{ticker}/candlesticks
+    ///
+    /// The API caps the number of candlesticks returned per request. When the requested
+    /// range would exceed that cap it is cut short and `adjusted_end_ts` reports where it
+    /// ended; use [`EventCandlesticks::is_truncated`] to check and request the remainder.
+    ///
+    /// # Arguments
+    /// * `series_ticker` - The series that contains the event.
+    /// * `event_ticker` - The event ticker.
+    /// * `start_ts` - Start timestamp (Unix seconds).
+    /// * `end_ts` - End timestamp (Unix seconds).
+    /// * `period_interval` - Length of each candlestick.
     pub async fn get_event_candlesticks(
         &self,
+        series_ticker: &str,
         event_ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+        period_interval: PeriodInterval,
     ) -> Result<EventCandlesticks, KalshiError> {
-        let path = format!("/events/{}/candlesticks", event_ticker);
-        let url = self.build_url(&path)?;
+        let path = format!(
+            "/series/{}/events/{}/candlesticks",
+            series_ticker, event_ticker
+        );
+        let mut params = Vec::new();
+        add_param!(params, "start_ts", Some(start_ts));
+        add_param!(params, "end_ts", Some(end_ts));
+        add_param!(params, "period_interval", Some(period_interval));
+
+        let url = self.build_url_with_params(&path, params)?;
         let result: EventCandlesticks = self.http_get(url).await?;
+        if result.is_truncated(end_ts) {
+            debug!(
+                "Event candlesticks for {} truncated at {:?} (requested end {})",
+                event_ticker, result.adjusted_end_ts, end_ts
+            );
+        }
         Ok(result)
     }
 
@@ -47,7 +184,7 @@ impl Kalshi {
             series_ticker, event_ticker
         );
         let mut params = Vec::new();
-        
+
         // Percentiles are sent as multiple query params with the same name 'percentiles'
         for p in percentiles {
             params.push(("percentiles", p.to_string()));
@@ -69,31 +206,85 @@ impl Kalshi {
         event_ticker: &str,
         with_nested_markets: bool,
     ) -> Result<Vec<crate::Market>, KalshiError> {
+        let resp = self.fetch_event(event_ticker, with_nested_markets).await?;
+        // Depending on `with_nested_markets` the API returns markets either inside the
+        // event or alongside it.
+        Ok(resp.event.markets.or(resp.markets).unwrap_or_default())
+    }
+
+    async fn fetch_event(
+        &self,
+        event_ticker: &str,
+        with_nested_markets: bool,
+    ) -> Result<GetEventResponse, KalshiError> {
         let path = format!("/events/{}", event_ticker);
         let mut params = Vec::new();
         add_param!(params, "with_nested_markets", Some(with_nested_markets));
 
         let url = self.build_url_with_params(&path, params)?;
-        let result: EventWithMarketsResponse = self.http_get(url).await?;
-        Ok(result.markets)
+        self.http_get(url).await
     }
 }
 
 // PRIVATE RESPONSES
 
+#[derive(Debug, Deserialize)]
+struct GetEventsResponse {
+    pub events: Vec<crate::Event>,
+    pub cursor: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct ForecastHistoryResponse {
     #[serde(rename = "forecast_history")]
     pub forecast_history: Vec<ForecastPercentilesSeries>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct EventWithMarketsResponse {
-    pub markets: Vec<crate::Market>,
+#[derive(Debug, Deserialize)]
+struct GetEventResponse {
+    pub event: crate::Event,
+    pub markets: Option<Vec<crate::Market>>,
 }
 
 // PUBLIC STRUCTS
 
+/// Filters and pagination for [`Kalshi::get_events`].
+#[derive(Debug, Clone, Default)]
+pub struct GetEventsParams {
+    /// Number of results per page (1-200, API default 200).
+    pub limit: Option<i64>,
+    /// Cursor returned by a previous page.
+    pub cursor: Option<String>,
+    pub series_ticker: Option<String>,
+    pub status: Option<EventStatus>,
+    /// If true, each event includes its markets.
+    pub with_nested_markets: bool,
+    /// Only events with at least one market closing after this Unix timestamp.
+    pub min_close_ts: Option<i64>,
+    /// Only events whose metadata changed after this Unix timestamp.
+    pub min_updated_ts: Option<i64>,
+}
+
+/// Status filter accepted by [`Kalshi::get_events`].
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStatus {
+    Open,
+    Closed,
+    Settled,
+}
+
+impl fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            EventStatus::Open => "open",
+            EventStatus::Closed => "closed",
+            EventStatus::Settled => "settled",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Event metadata, including competition, images, and settlement sources.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EventMetadata {
@@ -115,11 +306,78 @@ pub struct MarketMetadata {
 /// Aggregated candlestick data across all markets in an event.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EventCandlesticks {
+    /// Tickers of the markets in the event.
     pub market_tickers: Vec<String>,
+    /// One candlestick series per entry in `market_tickers`, in the same order.
     pub market_candlesticks: Vec<Vec<MarketCandlestick>>,
+    /// End of the range actually returned, if the API had to shorten the request.
     pub adjusted_end_ts: Option<i64>,
 }
 
+impl EventCandlesticks {
+    /// Returns true if the API stopped before `requested_end_ts`, meaning the remainder
+    /// of the range must be fetched with a follow-up request starting at `adjusted_end_ts`.
+    pub fn is_truncated(&self, requested_end_ts: i64) -> bool {
+        self.adjusted_end_ts
+            .is_some_and(|adjusted| adjusted < requested_end_ts)
+    }
+}
+
+/// Length of each candlestick period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeriodInterval {
+    OneMinute,
+    OneHour,
+    OneDay,
+}
+
+impl PeriodInterval {
+    /// Returns the period length in minutes, as expected by the API.
+    pub const fn as_minutes(&self) -> i64 {
+        match self {
+            PeriodInterval::OneMinute => 1,
+            PeriodInterval::OneHour => 60,
+            PeriodInterval::OneDay => 1440,
+        }
+    }
+}
+
+impl fmt::Display for PeriodInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_minutes())
+    }
+}
+
+impl TryFrom<i64> for PeriodInterval {
+    type Error = KalshiError;
+
+    /// Converts a period length in minutes, rejecting anything other than 1, 60 or 1440.
+    fn try_from(minutes: i64) -> Result<Self, Self::Error> {
+        match minutes {
+            1 => Ok(PeriodInterval::OneMinute),
+            60 => Ok(PeriodInterval::OneHour),
+            1440 => Ok(PeriodInterval::OneDay),
+            other => Err(KalshiError::UserInputError(format!(
+                "Invalid period interval {} (expected 1, 60 or 1440 minutes)",
+                other
+            ))),
+        }
+    }
+}
+
+impl Serialize for PeriodInterval {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_minutes())
+    }
+}
+
+impl<'de> Deserialize<'de> for PeriodInterval {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let minutes = i64::deserialize(deserializer)?;
+        PeriodInterval::try_from(minutes).map_err(serde::de::Error::custom)
+    }
+}
+
 /// A single candlestick entry for a given market and period.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MarketCandlestick {
@@ -180,4 +438,4 @@ pub struct PercentilePoint {
     pub raw_numerical_forecast: f64,
     pub numerical_forecast: f64,
     pub formatted_forecast: String,
-}
\ No newline at end of file
+}
@@ -1,6 +1,17 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{collections::HashSet, time::Duration};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver},
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
 
 impl Kalshi {
     /// Asynchronously retrieves the current status of the exchange.
@@ -36,12 +47,128 @@ impl Kalshi {
     /// kalshi_instance.get_exchange_schedule().await.unwrap();
     /// ```
     pub async fn get_exchange_schedule(&self) -> Result<ExchangeSchedule, KalshiError> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return cache
+                .exchange_schedule
+                .get_or_fetch((), self.fetch_exchange_schedule())
+                .await;
+        }
+        self.fetch_exchange_schedule().await
+    }
+
+    async fn fetch_exchange_schedule(&self) -> Result<ExchangeSchedule, KalshiError> {
         let exchange_schedule_url = self.build_url("/exchange/schedule")?;
 
         let result: ExchangeScheduleResponse = self.http_get(exchange_schedule_url).await?;
         Ok(result.schedule)
     }
 
+    /// Spawns a background task that polls the exchange status and schedule and reports
+    /// state transitions, so strategies can pause or flatten without polling themselves.
+    ///
+    /// The exchange is assumed to be fully active before the first poll, so starting the
+    /// watcher while trading is halted immediately yields the corresponding pause event.
+    /// Each maintenance window is announced once, as soon as its start is within
+    /// `maintenance_lead_time`. Failed polls are logged and retried on the next tick.
+    ///
+    /// # Arguments
+    /// * `poll_interval` - Time between polls.
+    /// * `maintenance_lead_time` - How far ahead of a maintenance window to announce it.
+    ///
+    /// # Returns
+    /// A receiver for the transition events and the handle of the polling task. The task
+    /// stops on its own once the receiver is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) {
+    /// use kalshi::ExchangeStatusEvent;
+    /// use std::time::Duration;
+    ///
+    /// let (mut rx, _task) =
+    ///     k.watch_exchange_status(Duration::from_secs(15), Duration::from_secs(600));
+    /// while let Some(event) = rx.recv().await {
+    ///     if let ExchangeStatusEvent::TradingPaused = event {
+    ///         // cancel resting orders, stop quoting...
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn watch_exchange_status(
+        &self,
+        poll_interval: Duration,
+        maintenance_lead_time: Duration,
+    ) -> (UnboundedReceiver<ExchangeStatusEvent>, JoinHandle<()>) {
+        let (tx, rx) = unbounded_channel();
+        let kalshi = self.clone();
+        let handle = tokio::spawn(async move {
+            let lead_time =
+                chrono::Duration::from_std(maintenance_lead_time).unwrap_or(chrono::Duration::MAX);
+            let mut exchange_active = true;
+            let mut trading_active = true;
+            let mut announced_windows: HashSet<(String, String)> = HashSet::new();
+            let mut ticker = interval(poll_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if tx.is_closed() {
+                    break;
+                }
+                let mut events = Vec::new();
+
+                match kalshi.get_exchange_status().await {
+                    Ok(status) => {
+                        if exchange_active && !status.exchange_active {
+                            events.push(ExchangeStatusEvent::ExchangePaused {
+                                estimated_resume_time: status.exchange_estimated_resume_time,
+                            });
+                        } else if !exchange_active && status.exchange_active {
+                            events.push(ExchangeStatusEvent::ExchangeResumed);
+                        }
+                        if trading_active && !status.trading_active {
+                            events.push(ExchangeStatusEvent::TradingPaused);
+                        } else if !trading_active && status.trading_active {
+                            events.push(ExchangeStatusEvent::TradingResumed);
+                        }
+                        exchange_active = status.exchange_active;
+                        trading_active = status.trading_active;
+                    }
+                    Err(e) => warn!("Exchange status poll failed: {}", e),
+                }
+
+                match kalshi.get_exchange_schedule().await {
+                    Ok(schedule) => {
+                        let now = Utc::now();
+                        for window in schedule.maintenance_windows {
+                            let Ok(start) = DateTime::parse_from_rfc3339(&window.start_datetime)
+                            else {
+                                continue;
+                            };
+                            let start = start.with_timezone(&Utc);
+                            let key = (window.start_datetime.clone(), window.end_datetime.clone());
+                            if start > now
+                                && start - now <= lead_time
+                                && announced_windows.insert(key)
+                            {
+                                events.push(ExchangeStatusEvent::MaintenanceImminent(window));
+                            }
+                        }
+                    }
+                    Err(e) => warn!("Exchange schedule poll failed: {}", e),
+                }
+
+                for event in events {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        (rx, handle)
+    }
+
     /// Retrieves exchange announcements.
     pub async fn get_exchange_announcements(&self) -> Result<Vec<Announcement>, KalshiError> {
         let url = self.build_url("/exchange/announcements")?;
@@ -49,59 +176,23 @@ impl Kalshi {
         Ok(resp.announcements)
     }
 
-    /// Retrieves the timestamp when user data was last updated.
-    pub async fn get_user_data_timestamp(&self) -> Result<String, KalshiError> {
+    /// Retrieves the time at which portfolio data (balance, orders, fills, positions)
+    /// was last refreshed on the server.
+    ///
+    /// Maps to GET /exchange/user_data_timestamp.
+    ///
+    /// Comparing this against the time of a previous portfolio read tells a caller
+    /// whether re-fetching would return anything new.
+    ///
+    /// # Returns
+    /// - `Ok(DateTime<Utc>)`: The server-side as-of time.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    pub async fn get_user_data_timestamp(&self) -> Result<DateTime<Utc>, KalshiError> {
         let url = self.build_url("/exchange/user_data_timestamp")?;
         let resp: GetUserDataTimestampResponse = self.http_get(url).await?;
         Ok(resp.as_of_time)
     }
 
-    /// Retrieves a single milestone by its ID.
-    pub async fn get_milestone(&self, milestone_id: &str) -> Result<Milestone, KalshiError> {
-        let path = format!("/milestones/{}", milestone_id);
-        let url = self.build_url(&path)?;
-        let resp: GetMilestoneResponse = self.http_get(url).await?;
-        Ok(resp.milestone)
-    }
-
-    /// Retrieves milestones with optional filters.
-    pub async fn get_milestones(
-        &self,
-        limit: Option<i32>,
-        cursor: Option<String>,
-        category: Option<String>,
-        type_: Option<String>,
-    ) -> Result<(Vec<Milestone>, Option<String>), KalshiError> {
-        let mut params = Vec::new();
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "category", category);
-        add_param!(params, "type", type_);
-
-        let url = self.build_url_with_params("/milestones", params)?;
-        let resp: GetMilestonesResponse = self.http_get(url).await?;
-        Ok((resp.milestones, resp.cursor))
-    }
-
-    /// Retrieves live data for a specific milestone.
-    pub async fn get_live_data(&self, type_: &str, milestone_id: &str) -> Result<LiveData, KalshiError> {
-        let path = format!("/live_data/{}/milestone/{}", type_, milestone_id);
-        let url = self.build_url(&path)?;
-        let resp: GetLiveDataResponse = self.http_get(url).await?;
-        Ok(resp.live_data)
-    }
-
-    /// Retrieves live data for multiple milestones.
-    pub async fn get_live_datas(&self, milestone_ids: Vec<String>) -> Result<Vec<LiveData>, KalshiError> {
-        let mut params = Vec::new();
-        for id in milestone_ids {
-            params.push(("milestone_ids", id));
-        }
-        let url = self.build_url_with_params("/live_data/batch", params)?;
-        let resp: GetLiveDatasResponse = self.http_get(url).await?;
-        Ok(resp.live_datas)
-    }
-
     /// Retrieves incentive programs.
     pub async fn get_incentive_programs(
         &self,
@@ -123,7 +214,7 @@ impl Kalshi {
 }
 
 /// Represents the standard trading hours and maintenance windows of the exchange.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ExchangeSchedule {
     pub maintenance_windows: Vec<MaintenanceWindow>,
     pub standard_hours: Vec<WeeklySchedule>,
@@ -150,28 +241,7 @@ pub struct Announcement {
 
 #[derive(Debug, Deserialize, Serialize)]
 struct GetUserDataTimestampResponse {
-    pub as_of_time: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GetMilestoneResponse {
-    pub milestone: Milestone,
-}
-
-#[derive(Debug, Deserialize)]
-struct GetMilestonesResponse {
-    pub milestones: Vec<Milestone>,
-    pub cursor: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-struct GetLiveDataResponse {
-    pub live_data: LiveData,
-}
-
-#[derive(Debug, Deserialize)]
-struct GetLiveDatasResponse {
-    pub live_datas: Vec<LiveData>,
+    pub as_of_time: DateTime<Utc>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -180,30 +250,6 @@ struct GetIncentiveProgramsResponse {
     pub next_cursor: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct Milestone {
-    pub id: String,
-    pub category: String,
-    pub r#type: String,
-    pub start_date: String,
-    pub end_date: Option<String>,
-    pub related_event_tickers: Vec<String>,
-    pub title: String,
-    pub notification_message: String,
-    pub source_id: Option<String>,
-    pub source_ids: serde_json::Value,
-    pub details: serde_json::Value,
-    pub primary_event_tickers: Vec<String>,
-    pub last_updated_ts: String,
-}
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct LiveData {
-    pub r#type: String,
-    pub details: serde_json::Value,
-    pub milestone_id: String,
-}
-
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct IncentiveProgram {
     pub id: String,
@@ -228,14 +274,14 @@ pub struct ExchangeStatus {
 }
 
 /// A maintenance window during which the exchange may be unavailable.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MaintenanceWindow {
     pub start_datetime: String,
     pub end_datetime: String,
 }
 
 /// A weekly schedule with trading sessions for each day.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WeeklySchedule {
     pub start_time: String,
     pub end_time: String,
@@ -249,8 +295,25 @@ pub struct WeeklySchedule {
 }
 
 /// Represents the opening and closing times of the exchange for a single day.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DailySchedule {
     pub open_time: String,
     pub close_time: String,
 }
+
+/// State transitions reported by [`Kalshi::watch_exchange_status`].
+#[derive(Debug, Clone)]
+pub enum ExchangeStatusEvent {
+    /// The exchange stopped accepting any state changes, usually for maintenance.
+    ExchangePaused {
+        estimated_resume_time: Option<String>,
+    },
+    /// The exchange is accepting state changes again.
+    ExchangeResumed,
+    /// Trading was halted, either outside trading hours or by the exchange.
+    TradingPaused,
+    /// Trading is permitted again.
+    TradingResumed,
+    /// A scheduled maintenance window starts within the configured lead time.
+    MaintenanceImminent(MaintenanceWindow),
+}
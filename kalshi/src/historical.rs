@@ -20,13 +20,13 @@ impl Kalshi {
     /// * `ticker` - Market ticker.
     /// * `start_ts` - Start timestamp (Unix seconds).
     /// * `end_ts` - End timestamp (Unix seconds).
-    /// * `period_interval` - Length of each candlestick in minutes (1, 60, or 1440).
+    /// * `period_interval` - Length of each candlestick.
     pub async fn get_market_candlesticks_historical(
         &self,
         ticker: &str,
         start_ts: i64,
         end_ts: i64,
-        period_interval: i32,
+        period_interval: crate::PeriodInterval,
     ) -> Result<Vec<MarketCandlestickHistorical>, KalshiError> {
         let path = format!("/historical/markets/{}/candlesticks", ticker);
         let mut params = Vec::new();
@@ -138,7 +138,10 @@ impl Kalshi {
     /// Retrieves a single historical market by ticker.
     ///
     /// Maps to GET /historical/markets/{ticker}
-    pub async fn get_historical_market(&self, ticker: &str) -> Result<crate::market::Market, KalshiError> {
+    pub async fn get_historical_market(
+        &self,
+        ticker: &str,
+    ) -> Result<crate::market::Market, KalshiError> {
         let path = format!("/historical/markets/{}", ticker);
         let url = self.build_url(&path)?;
         let resp: HistoricalMarketResponse = self.http_get(url).await?;
@@ -233,4 +236,4 @@ pub struct PriceDistributionHistorical {
     pub mean: Option<String>,
     /// Close price from the previous period in dollars.
     pub previous: Option<String>,
-}
\ No newline at end of file
+}
@@ -1,53 +1,199 @@
-use crate::kalshi_error::KalshiError;
 use crate::kalshi_error::RequestError;
-use crate::utils::api_key_headers;
-use crate::KalshiAuth;
-use openssl::hash::MessageDigest;
-use openssl::pkey::PKey;
-use openssl::rsa::Padding;
-use openssl::sign::{RsaPssSaltlen, Signer};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::Method;
+use crate::kalshi_error::{ApiErrorCode, KalshiError};
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::utils::{api_key_headers, sign_request};
+use crate::{KalshiAuth, RateLimitInfo, SignedRequest};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 use reqwest::Url;
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
-use tracing::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::watch;
+use tracing::{debug, error, field, info, info_span, warn, Instrument};
 
 use super::Kalshi;
 
+#[cfg(all(
+    not(any(feature = "native-tls", feature = "rustls")),
+    not(target_arch = "wasm32")
+))]
+compile_error!("either the `native-tls` or the `rustls` feature must be enabled");
+
+// Enabling both TLS features is allowed, and `rustls` wins, like `rust-signing` over
+// `openssl-signing`. Cargo unifies features across the dependency graph, so one crate
+// asking for `rustls` while another keeps the defaults must still build, and so must
+// `--all-features`.
+
+/// Returns a REST client builder using the TLS backend selected by the crate features.
+pub(crate) fn client_builder() -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder();
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    let builder = builder.use_rustls_tls();
+    builder
+}
+
+/// Response headers naming the request, in order of preference. Kalshi support can
+/// look a request up by this id.
+const REQUEST_ID_HEADERS: [&str; 3] = ["x-request-id", "x-trace-id", "x-amzn-trace-id"];
+
+/// Headers of a response that are kept for decoding it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResponseMeta {
+    rate_limit: Option<RateLimitInfo>,
+    request_id: Option<String>,
+}
+
+impl ResponseMeta {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        ResponseMeta {
+            rate_limit: RateLimitInfo::from_headers(headers),
+            request_id: request_id(headers),
+        }
+    }
+}
+
+/// Returns the id the exchange assigned to a request, if the response names one.
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    REQUEST_ID_HEADERS.iter().find_map(|name| {
+        headers
+            .get(*name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    })
+}
+
+/// Body of a non-success response: `{"error": {"code", "message", "service"}}`.
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    error: ApiErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorDetail {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+    service: Option<String>,
+}
+
 impl Kalshi {
-    fn auth_headers(&self, path: &str, method: Method) -> HeaderMap {
+    /// Signs a request and returns its authentication headers.
+    fn auth_headers(&self, path: &str, method: Method) -> Result<HeaderMap, KalshiError> {
         let mut headers = HeaderMap::new();
-        match &self.auth {
-            KalshiAuth::ApiKey { key_id, key, .. } => {
-                let pkey = PKey::private_key_from_pem(key.as_bytes()).unwrap();
-                let mut signer = Signer::new(MessageDigest::sha256(), &pkey).unwrap();
-                signer.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
-                signer.set_rsa_mgf1_md(MessageDigest::sha256()).unwrap();
-                signer
-                    .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
-                    .unwrap();
-                let api_headers = api_key_headers(key_id, &mut signer, path, method).unwrap();
+        match &*self.auth() {
+            KalshiAuth::ApiKey { key_id, signer, .. } => {
+                let api_headers =
+                    api_key_headers(key_id, signer, path, method, self.clock_offset_ms()).map_err(
+                        |e| KalshiError::AuthError(format!("Unable to sign request: {}", e)),
+                    )?;
                 for (key_str, value_string) in api_headers {
-                    headers.insert(
-                        HeaderName::from_static(key_str),
-                        HeaderValue::from_str(&value_string).unwrap(),
-                    );
+                    let value = HeaderValue::from_str(&value_string).map_err(|e| {
+                        KalshiError::AuthError(format!("Invalid {} header: {}", key_str, e))
+                    })?;
+                    headers.insert(HeaderName::from_static(key_str), value);
                 }
             }
         }
-        headers
+        Ok(headers)
+    }
+
+    /// Opens `connections` pooled connections ahead of time with cheap exchange status
+    /// calls, so the first orders after startup or an idle period do not pay for the
+    /// TCP and TLS handshakes.
+    pub async fn warm_up(&self, connections: usize) -> Result<(), KalshiError> {
+        let url = self.build_url("/exchange/status")?;
+        let requests = (0..connections.max(1)).map(|_| self.send(Method::GET, &url, None));
+        futures_util::future::try_join_all(requests).await?;
+        Ok(())
+    }
+
+    /// Signs a request the way every API call is signed and returns the signed message
+    /// and resulting headers, without sending anything.
+    ///
+    /// Meant for diagnosing 401 errors. The same message is also emitted as the
+    /// `signing_string` field of a `TRACE` level event for every request.
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method of the request.
+    /// * `path` - Path relative to the API base URL, e.g. `"/portfolio/balance"`.
+    pub fn debug_signature(
+        &self,
+        method: Method,
+        path: &str,
+    ) -> Result<SignedRequest, KalshiError> {
+        let url = self.build_url(path)?;
+        match &*self.auth() {
+            KalshiAuth::ApiKey { key_id, signer, .. } => {
+                sign_request(key_id, signer, url.path(), method, self.clock_offset_ms())
+                    .map_err(|e| KalshiError::AuthError(format!("Unable to sign request: {}", e)))
+            }
+        }
     }
 
     pub async fn http_get<T: DeserializeOwned>(&self, url: Url) -> Result<T, KalshiError> {
-        let resp = self
-            .client
-            .get(url.clone())
-            .headers(self.auth_headers(url.path(), Method::GET))
-            .send()
-            .await?;
+        if let Some(inflight) = &self.inflight {
+            let (status, meta, body) = inflight.get(self, &url).await?;
+            return self.decode_response("GET", &url, None, status, meta, &body);
+        }
+
+        let resp = self.send_get(&url).await?;
+        self.decode_response(
+            "GET",
+            &url,
+            None,
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
+    }
+
+    /// Sends an authenticated GET to any API path and returns the untyped JSON response.
+    ///
+    /// An escape hatch for endpoints this crate does not wrap yet. Requests go through
+    /// the same signing, logging and error handling as the typed methods.
+    ///
+    /// # Arguments
+    /// * `path` - Path relative to the API base URL, e.g. `"/exchange/status"`.
+    /// * `params` - Query parameters to append.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// let status = k.get_raw("/exchange/status", vec![]).await?;
+    /// tracing::debug!("trading active: {}", status["trading_active"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(
+        &self,
+        path: &str,
+        params: Vec<(&str, String)>,
+    ) -> Result<serde_json::Value, KalshiError> {
+        self.get_custom(path, params).await
+    }
 
-        self.process_response::<T>("GET", &url, None, resp).await
+    /// Sends an authenticated GET to any API path and deserializes the response into `T`.
+    ///
+    /// Like [`get_raw`](Kalshi::get_raw), but for callers that define their own response type.
+    pub async fn get_custom<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: Vec<(&str, String)>,
+    ) -> Result<T, KalshiError> {
+        let url = self.build_url_with_params(path, params)?;
+        self.http_get(url).await
+    }
+
+    // Internal: send a GET and read the raw response, for sharing between coalesced callers.
+    async fn fetch_get_bytes(&self, url: &Url) -> Result<CoalescedResponse, KalshiError> {
+        let resp = self.send_get(url).await?;
+        let meta = ResponseMeta::from_headers(&resp.headers);
+        Ok((resp.status, meta, Arc::from(resp.body)))
     }
 
     pub async fn http_post<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
@@ -55,18 +201,17 @@ impl Kalshi {
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let resp = self
-            .client
-            .post(url.clone())
-            .headers(self.auth_headers(url.path(), Method::POST))
-            .json(body)
-            .send()
-            .await?;
-
-        let req_body_string =
-            serde_json::to_string(body).unwrap_or_else(|_| "<unserializable body>".to_string());
-        self.process_response::<T>("POST", &url, Some(req_body_string), resp)
-            .await
+        let body = encode_body(body)?;
+        let req_body_string = String::from_utf8_lossy(&body).into_owned();
+        let resp = self.send(Method::POST, &url, Some(body)).await?;
+        self.decode_response(
+            "POST",
+            &url,
+            Some(req_body_string),
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
     }
 
     pub async fn http_put<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
@@ -74,29 +219,47 @@ impl Kalshi {
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let resp = self
-            .client
-            .put(url.clone())
-            .headers(self.auth_headers(url.path(), Method::PUT))
-            .json(body)
-            .send()
-            .await?;
+        let body = encode_body(body)?;
+        let req_body_string = String::from_utf8_lossy(&body).into_owned();
+        let resp = self.send(Method::PUT, &url, Some(body)).await?;
+        self.decode_response(
+            "PUT",
+            &url,
+            Some(req_body_string),
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
+    }
 
-        let req_body_string =
-            serde_json::to_string(body).unwrap_or_else(|_| "<unserializable body>".to_string());
-        self.process_response::<T>("PUT", &url, Some(req_body_string), resp)
-            .await
+    pub async fn http_patch<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let body = encode_body(body)?;
+        let req_body_string = String::from_utf8_lossy(&body).into_owned();
+        let resp = self.send(Method::PATCH, &url, Some(body)).await?;
+        self.decode_response(
+            "PATCH",
+            &url,
+            Some(req_body_string),
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
     }
 
     pub async fn http_delete<T: DeserializeOwned>(&self, url: Url) -> Result<T, KalshiError> {
-        let resp = self
-            .client
-            .delete(url.clone())
-            .headers(self.auth_headers(url.path(), Method::DELETE))
-            .send()
-            .await?;
-
-        self.process_response::<T>("DELETE", &url, None, resp).await
+        let resp = self.send(Method::DELETE, &url, None).await?;
+        self.decode_response(
+            "DELETE",
+            &url,
+            None,
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
     }
 
     pub async fn http_delete_with_body<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
@@ -104,31 +267,142 @@ impl Kalshi {
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let resp = self
-            .client
-            .delete(url.clone())
-            .headers(self.auth_headers(url.path(), Method::DELETE))
-            .json(body)
-            .send()
-            .await?;
-
-        let req_body_string =
-            serde_json::to_string(body).unwrap_or_else(|_| "<unserializable body>".to_string());
-        self.process_response::<T>("DELETE", &url, Some(req_body_string), resp)
-            .await
-    }
-
-    // Internal: process an HTTP response with debug/info logging and JSON deserialization.
-    async fn process_response<T: DeserializeOwned>(
+        let body = encode_body(body)?;
+        let req_body_string = String::from_utf8_lossy(&body).into_owned();
+        let resp = self.send(Method::DELETE, &url, Some(body)).await?;
+        self.decode_response(
+            "DELETE",
+            &url,
+            Some(req_body_string),
+            resp.status,
+            ResponseMeta::from_headers(&resp.headers),
+            &resp.body,
+        )
+    }
+
+    // Internal: send a GET, hedged with a second request if the first one is slow.
+    async fn send_get(&self, url: &Url) -> Result<HttpResponse, KalshiError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(delay) = self.hedge_delay {
+            use futures_util::future::{select, Either};
+
+            let first = Box::pin(self.send(Method::GET, url, None));
+            let first = match select(first, Box::pin(tokio::time::sleep(delay))).await {
+                Either::Left((res, _)) => return res,
+                Either::Right((_, first)) => first,
+            };
+            debug!("Hedging GET {} after {:?}", url, delay);
+            let second = Box::pin(self.send(Method::GET, url, None));
+            // Take the first success; if one request fails, wait for the other.
+            return match select(first, second).await {
+                Either::Left((Ok(resp), _)) | Either::Right((Ok(resp), _)) => Ok(resp),
+                Either::Left((Err(_), other)) | Either::Right((Err(_), other)) => other.await,
+            };
+        }
+        self.send(Method::GET, url, None).await
+    }
+
+    // Internal: sign a request and send it through the transport.
+    pub(crate) async fn send(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, KalshiError> {
+        let Some(token) = &self.cancellation else {
+            return self.send_with_retries(method, url, body).await;
+        };
+        let description = format!("{} {} was cancelled", method, url);
+        tokio::select! {
+            biased;
+            _ = token.cancelled() => Err(KalshiError::Cancelled(description)),
+            res = self.send_with_retries(method, url, body) => res,
+        }
+    }
+
+    /// Sends a request once, within the concurrency limit and timeout.
+    pub(crate) async fn send_once(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, KalshiError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.check()?;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(&method).await;
+        }
+        let _permit = self.acquire_slot(&method, url).await;
+        let mut headers = self.default_headers.clone();
+        headers.extend(self.auth_headers(url.path(), method.clone())?);
+        if body.is_some() {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        }
+        let span = info_span!(
+            "kalshi_request",
+            method = %method,
+            path = url.path(),
+            status = field::Empty,
+            request_id = field::Empty,
+        );
+        let request = self
+            .transport
+            .send(HttpRequest {
+                method: method.clone(),
+                url: url.clone(),
+                headers,
+                body,
+            })
+            .instrument(span.clone());
+        #[cfg(not(target_arch = "wasm32"))]
+        let resp = {
+            let res = match self.request_timeout {
+                Some(timeout) => {
+                    tokio::time::timeout(timeout, request)
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(KalshiError::Timeout(format!(
+                                "{} {} did not complete within {:?}",
+                                method, url, timeout
+                            )))
+                        })
+                }
+                None => request.await,
+            };
+            if let Some(breaker) = &self.circuit_breaker {
+                breaker.record(&res);
+            }
+            res?
+        };
+        #[cfg(target_arch = "wasm32")]
+        let resp = request.await?;
+        span.record("status", resp.status.as_u16());
+        if let Some(request_id) = request_id(&resp.headers) {
+            span.record("request_id", request_id.as_str());
+        }
+        self.observe_rate_limit(&resp.headers);
+        Ok(resp)
+    }
+
+    // Internal: log a response body and deserialize it, turning non-success statuses into errors.
+    fn decode_response<T: DeserializeOwned>(
         &self,
         method: &str,
         url: &Url,
         request_body: Option<String>,
-        resp: reqwest::Response,
+        status: StatusCode,
+        meta: ResponseMeta,
+        bytes: &[u8],
     ) -> Result<T, KalshiError> {
-        let status = resp.status();
-        let bytes = resp.bytes().await?;
-
+        let _span = meta
+            .request_id
+            .as_deref()
+            .map(|request_id| info_span!("kalshi_response", request_id).entered());
+        let request_body = request_body.map(|body| self.redaction.log_body(body.as_bytes()));
+        let response_body = self.redaction.log_body(bytes);
         if !status.is_success() {
             match request_body {
                 Some(body) => {
@@ -139,7 +413,7 @@ impl Kalshi {
                             url,
                             status,
                             body,
-                            String::from_utf8_lossy(&bytes)
+                            response_body
                         );
                     } else if status.is_server_error() {
                         error!(
@@ -148,7 +422,7 @@ impl Kalshi {
                             url,
                             status,
                             body,
-                            String::from_utf8_lossy(&bytes)
+                            response_body
                         );
                     } else {
                         info!(
@@ -157,7 +431,7 @@ impl Kalshi {
                             url,
                             status,
                             body,
-                            String::from_utf8_lossy(&bytes)
+                            response_body
                         );
                     }
                 }
@@ -165,49 +439,61 @@ impl Kalshi {
                     if status.is_client_error() {
                         warn!(
                             "HTTP {} non-success: url={}, status={}, response_body={}",
-                            method,
-                            url,
-                            status,
-                            String::from_utf8_lossy(&bytes)
+                            method, url, status, response_body
                         );
                     } else if status.is_server_error() {
                         error!(
                             "HTTP {} non-success: url={}, status={}, response_body={}",
-                            method,
-                            url,
-                            status,
-                            String::from_utf8_lossy(&bytes)
+                            method, url, status, response_body
                         );
                     } else {
                         info!(
                             "HTTP {} non-success: url={}, status={}, response_body={}",
-                            method,
-                            url,
-                            status,
-                            String::from_utf8_lossy(&bytes)
+                            method, url, status, response_body
                         );
                     }
                 }
             }
         } else {
             debug!("{} {} -> {}", method, url, status);
-            debug!("Response body: {}", String::from_utf8_lossy(&bytes));
+            debug!("Response body: {}", response_body);
         }
 
         if !status.is_success() {
-            return Err(KalshiError::InternalError(format!(
-                "Non-success status {}. Body: {}",
-                status,
-                String::from_utf8_lossy(&bytes)
-            )));
+            return Err(match serde_json::from_slice::<ApiErrorBody>(bytes) {
+                Ok(ApiErrorBody { error }) => KalshiError::ApiError {
+                    status,
+                    code: error.code.as_str().into(),
+                    message: error.message,
+                    service: error.service,
+                    rate_limit: meta.rate_limit.map(Box::new),
+                    request_id: meta.request_id,
+                },
+                Err(_) => KalshiError::ApiError {
+                    status,
+                    code: ApiErrorCode::Other(String::new()),
+                    message: self.redaction.redact(bytes),
+                    service: None,
+                    rate_limit: meta.rate_limit.map(Box::new),
+                    request_id: meta.request_id,
+                },
+            });
         }
 
-        serde_json::from_slice::<T>(&bytes).map_err(|e| {
-            KalshiError::InternalError(format!(
-                "Deserialize error: {}. Body: {}",
-                e,
-                String::from_utf8_lossy(&bytes)
-            ))
+        decode_json::<T>(bytes).map_err(|(path, source)| {
+            warn!(
+                "HTTP {} response did not decode: url={}, path={}, error={}, response_body={}",
+                method,
+                url,
+                path.as_deref().unwrap_or("<root>"),
+                source,
+                response_body
+            );
+            KalshiError::JsonError {
+                context: format!("Unable to decode response of {} {}", method, url.path()),
+                path,
+                source,
+            }
         })
     }
 
@@ -227,17 +513,134 @@ impl Kalshi {
         params: Vec<(&str, String)>,
     ) -> Result<Url, KalshiError> {
         let base_url_str = format!("{}{}", self.base_url, base_path);
-        Url::parse_with_params(&base_url_str, &params).map_err(|err| {
-            // Convert url::ParseError to KalshiError::RequestError
-            KalshiError::RequestError(RequestError::UrlParseError(err))
-        })
+        Url::parse_with_params(&base_url_str, &params).map_err(|err| RequestError::from(err).into())
     }
 
     pub fn build_url(&self, base_path: &str) -> Result<Url, KalshiError> {
         let base_url_str = format!("{}{}", self.base_url, base_path);
-        Url::parse(&base_url_str).map_err(|err| {
-            // Convert url::ParseError to KalshiError::RequestError
-            KalshiError::RequestError(RequestError::UrlParseError(err))
-        })
+        Url::parse(&base_url_str).map_err(|err| RequestError::from(err).into())
+    }
+}
+
+/// Decodes JSON, returning the path of the value that failed to decode along with the
+/// error, e.g. `markets[3].close_time`, or `None` for errors at the root.
+pub(crate) fn decode_json<T: DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<T, (Option<String>, serde_json::Error)> {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        ((path != ".").then_some(path), err.into_inner())
+    })?;
+    deserializer.end().map_err(|err| (None, err))?;
+    Ok(value)
+}
+
+fn encode_body<B: Serialize + ?Sized>(body: &B) -> Result<Vec<u8>, KalshiError> {
+    serde_json::to_vec(body).map_err(|source| KalshiError::JsonError {
+        context: "Unable to serialize request body".to_string(),
+        path: None,
+        source,
+    })
+}
+
+// REQUEST COALESCING
+// -----------------------------------------------
+
+type CoalescedResponse = (StatusCode, ResponseMeta, Arc<[u8]>);
+type SharedResponse = Result<CoalescedResponse, Arc<KalshiError>>;
+
+/// Tracks in-flight GET requests so identical concurrent requests share one response.
+#[derive(Debug, Default)]
+pub(crate) struct InflightRequests {
+    requests: Mutex<HashMap<String, watch::Receiver<Option<SharedResponse>>>>,
+}
+
+/// Removes a request from the in-flight map when the leading caller finishes or is dropped.
+struct InflightGuard<'a> {
+    inflight: &'a InflightRequests,
+    key: String,
+}
+
+impl Drop for InflightGuard<'_> {
+    fn drop(&mut self) {
+        self.inflight.requests.lock().unwrap().remove(&self.key);
+    }
+}
+
+impl InflightRequests {
+    /// Sends a GET for `url`, or waits for an identical request that is already in flight.
+    ///
+    /// The first caller for a URL performs the request. Callers arriving while it is
+    /// running receive a copy of its response or error, and if the first caller is
+    /// cancelled they fall back to their own request.
+    async fn get(&self, kalshi: &Kalshi, url: &Url) -> Result<CoalescedResponse, KalshiError> {
+        let key = url.to_string();
+        let leader = {
+            let mut requests = self.requests.lock().unwrap();
+            match requests.get(&key) {
+                Some(rx) => Err(rx.clone()),
+                None => {
+                    let (tx, rx) = watch::channel(None);
+                    requests.insert(key.clone(), rx);
+                    Ok(tx)
+                }
+            }
+        };
+
+        match leader {
+            Ok(tx) => {
+                let guard = InflightGuard {
+                    inflight: self,
+                    key,
+                };
+                let result = kalshi.fetch_get_bytes(url).await;
+                // Stop taking followers, so the leader keeps its own error when none joined.
+                drop(guard);
+                if tx.receiver_count() == 0 {
+                    return result;
+                }
+                match result {
+                    Ok(response) => {
+                        tx.send_replace(Some(Ok(response.clone())));
+                        Ok(response)
+                    }
+                    Err(e) => {
+                        let shared = Arc::new(e);
+                        tx.send_replace(Some(Err(shared.clone())));
+                        Err(shared.share())
+                    }
+                }
+            }
+            Err(mut rx) => {
+                debug!("Coalescing GET {} with an in-flight request", url);
+                let shared = match rx.wait_for(|r| r.is_some()).await {
+                    Ok(shared) => shared.clone(),
+                    Err(_) => None,
+                };
+                match shared {
+                    Some(Ok(response)) => Ok(response),
+                    Some(Err(e)) => Err(e.share()),
+                    None => kalshi.fetch_get_bytes(url).await,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn decode_json_reports_the_failing_path() {
+        let body = br#"{"markets": [{"volume": 1}, {"volume": "many"}]}"#;
+        let (path, _) =
+            decode_json::<HashMap<String, Vec<HashMap<String, i64>>>>(body).unwrap_err();
+        assert_eq!(path.as_deref(), Some("markets[1].volume"));
+
+        let (path, _) = decode_json::<Vec<i64>>(b"{}").unwrap_err();
+        assert_eq!(path, None);
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 use std::{
-    error::Error,
     fmt::{self, Display},
+    sync::Arc,
 };
+use thiserror::Error;
 
 // CUSTOM ERROR STRUCTS + ENUMS
 // -----------------------------------------------
@@ -13,105 +14,376 @@ use std::{
 /// the entire Kalshi module.
 ///
 /// Represents various errors that can occur when interacting with the Kalshi API.
-#[derive(Debug)]
+/// Underlying errors are kept as [`source`](std::error::Error::source) and left out of
+/// the `Display` output, so error reporters such as `anyhow` show the full chain without
+/// repeating it. New variants may be added in minor releases.
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum KalshiError {
     /// Errors that occur during HTTP requests. This includes connectivity issues,
     /// response serialization problems, and HTTP status errors.
-    RequestError(RequestError),
+    #[error(transparent)]
+    RequestError(#[from] RequestError),
     /// Errors caused by incorrect or invalid user input.
+    #[error("User Input Error: {0}")]
     UserInputError(String),
+    /// Errors building authentication for a request, such as an unusable private key.
+    #[error("Authentication Error: {0}")]
+    AuthError(String),
+    /// A request did not complete within its timeout, either the deadline set with
+    /// [`Kalshi::with_timeout`](crate::Kalshi::with_timeout) or a timeout of the HTTP
+    /// client. The request may still have reached the exchange.
+    #[error("Timeout: {0}")]
+    Timeout(String),
+    /// A request was aborted because the cancellation token set with
+    /// [`Kalshi::with_cancellation`](crate::Kalshi::with_cancellation) fired. The request
+    /// may still have reached the exchange.
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
+    /// A request was not sent because the circuit breaker set with
+    /// [`Kalshi::with_circuit_breaker`](crate::Kalshi::with_circuit_breaker) is open.
+    #[error("Circuit Open: {0}")]
+    CircuitOpen(String),
+    /// Kalshi answered with a non-success status. `code` and `message` come from the
+    /// error body Kalshi sends; when the body is not in that format, `code` is
+    /// [`ApiErrorCode::Other`] with an empty string and `message` holds the body.
+    #[error("API Error, Status code: {status}{}: {message}{}", api_code_suffix(code), request_id.as_ref().map(|id| format!(" (request id {})", id)).unwrap_or_default())]
+    ApiError {
+        /// HTTP status of the response.
+        status: reqwest::StatusCode,
+        /// Machine-readable error code, e.g. [`ApiErrorCode::InsufficientBalance`].
+        code: ApiErrorCode,
+        /// Human-readable description of the error.
+        message: String,
+        /// Kalshi service that produced the error, if reported.
+        service: Option<String>,
+        /// Rate limit headers of the response, if it had any.
+        rate_limit: Option<Box<crate::RateLimitInfo>>,
+        /// Id of the request from the `X-Request-Id` or a trace header of the response,
+        /// worth quoting in support tickets.
+        request_id: Option<String>,
+    },
+    /// A request body could not be encoded, or a response or file could not be
+    /// decoded, as JSON.
+    #[error("JSON Error: {context}{}", path.as_ref().map(|path| format!(" at {}", path)).unwrap_or_default())]
+    JsonError {
+        /// What was being encoded or decoded.
+        context: String,
+        /// Path of the value that failed to decode, e.g. `markets[3].close_time`.
+        path: Option<String>,
+        source: serde_json::Error,
+    },
+    /// Reading a file, such as a private key or a configuration file, failed.
+    #[error("IO Error: {context}")]
+    IoError {
+        /// What was being read.
+        context: String,
+        source: std::io::Error,
+    },
     /// Errors representing unexpected internal issues or situations that are not supposed to happen.
+    #[error("Internal Error: {0}")]
     InternalError(String),
+    /// A paginated collection exceeded the caps given to [`Paginated::collect_all`](crate::Paginated::collect_all).
+    #[error("Pagination Limit Reached: stopped after {items} items across {pages} pages; narrow the request filters or raise the limits")]
+    PaginationLimitReached {
+        /// Number of items collected before giving up.
+        items: usize,
+        /// Number of pages fetched before giving up.
+        pages: usize,
+    },
+    /// Errors reported by the websocket client, including dropped connections.
+    #[cfg(feature = "websockets")]
+    #[error(transparent)]
+    WebsocketError(crate::websockets::client::KalshiWebsocketError),
+    /// An error shared by identical GET requests coalesced with
+    /// [`Kalshi::with_request_coalescing`](crate::Kalshi::with_request_coalescing), for
+    /// errors that cannot be copied to each caller. The helper methods such as
+    /// [`status`](KalshiError::status) and [`is_retryable`](KalshiError::is_retryable)
+    /// look through it.
+    #[error(transparent)]
+    Shared(Arc<KalshiError>),
     // TODO: add error type specifically for joining threads together.
 }
 
-impl Display for KalshiError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+fn api_code_suffix(code: &ApiErrorCode) -> String {
+    if code.as_str().is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", code)
+    }
+}
+
+/// Statuses that signal a transient condition worth retrying.
+pub(crate) const RETRYABLE_STATUSES: [u16; 4] = [429, 502, 503, 504];
+
+impl KalshiError {
+    /// Returns the Kalshi error code if this is an [`ApiError`](KalshiError::ApiError).
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{ApiErrorCode, KalshiError};
+    /// # fn example(err: KalshiError) {
+    /// if err.api_code() == Some(&ApiErrorCode::InsufficientBalance) {
+    ///     // shrink the order
+    /// }
+    /// # }
+    /// ```
+    pub fn api_code(&self) -> Option<&ApiErrorCode> {
+        match self {
+            KalshiError::ApiError { code, .. } => Some(code),
+            KalshiError::Shared(e) => e.api_code(),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the request that failed, if Kalshi reported one, for quoting in
+    /// support tickets and finding the request in exchange-side logs.
+    pub fn request_id(&self) -> Option<&str> {
         match self {
-            KalshiError::RequestError(e) => write!(f, "HTTP Error: {}", e),
-            KalshiError::UserInputError(e) => write!(f, "User Input Error: {}", e),
-            KalshiError::InternalError(e) => write!(f, "INTERNAL ERROR, PLEASE EMAIL DEVELOPER OR MAKE A NEW ISSUE ON THE CRATE'S REPOSITORY: https://github.com/dpeachpeach/kalshi-rust. Specific Error: {}", e)
+            KalshiError::ApiError { request_id, .. } => request_id.as_deref(),
+            KalshiError::Shared(e) => e.request_id(),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status of the response that caused this error, if there was one.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            KalshiError::ApiError { status, .. } => Some(*status),
+            KalshiError::RequestError(
+                RequestError::SerializationError(e)
+                | RequestError::ClientError(e)
+                | RequestError::ServerError(e),
+            ) => e.status(),
+            KalshiError::Shared(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if Kalshi rejected the request for exceeding the rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+            || self.api_code() == Some(&ApiErrorCode::TooManyRequests)
+    }
+
+    /// Returns `true` for failures that may succeed when the request is sent again: rate
+    /// limiting, 502, 503 and 504 responses, timeouts and connection errors.
+    ///
+    /// Whether retrying is safe also depends on the request: an order that timed out may
+    /// still have reached the exchange.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            KalshiError::Timeout(_) => true,
+            KalshiError::Shared(e) => e.is_retryable(),
+            KalshiError::RequestError(
+                RequestError::ServerError(e) | RequestError::ClientError(e),
+            ) if e.status().is_none() => {
+                #[cfg(not(target_arch = "wasm32"))]
+                let connect = e.is_connect();
+                // The browser does not say whether a fetch failed while connecting.
+                #[cfg(target_arch = "wasm32")]
+                let connect = false;
+                connect || e.is_request()
+            }
+            _ => self
+                .status()
+                .is_some_and(|status| RETRYABLE_STATUSES.contains(&status.as_u16())),
+        }
+    }
+
+    /// Returns how long Kalshi asked to wait before retrying, from the `Retry-After`
+    /// header of an [`ApiError`](KalshiError::ApiError).
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            KalshiError::ApiError {
+                rate_limit: Some(rate_limit),
+                ..
+            } => rate_limit.retry_after,
+            KalshiError::Shared(e) => e.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of this error for another caller, wrapping it in
+    /// [`Shared`](KalshiError::Shared) when it holds a source that cannot be copied.
+    pub(crate) fn share(self: &Arc<Self>) -> KalshiError {
+        match self.as_ref() {
+            KalshiError::RequestError(RequestError::UrlParseError(e)) => {
+                RequestError::UrlParseError(*e).into()
+            }
+            KalshiError::UserInputError(msg) => KalshiError::UserInputError(msg.clone()),
+            KalshiError::AuthError(msg) => KalshiError::AuthError(msg.clone()),
+            KalshiError::Timeout(msg) => KalshiError::Timeout(msg.clone()),
+            KalshiError::Cancelled(msg) => KalshiError::Cancelled(msg.clone()),
+            KalshiError::CircuitOpen(msg) => KalshiError::CircuitOpen(msg.clone()),
+            KalshiError::ApiError {
+                status,
+                code,
+                message,
+                service,
+                rate_limit,
+                request_id,
+            } => KalshiError::ApiError {
+                status: *status,
+                code: code.clone(),
+                message: message.clone(),
+                service: service.clone(),
+                rate_limit: rate_limit.clone(),
+                request_id: request_id.clone(),
+            },
+            KalshiError::InternalError(msg) => KalshiError::InternalError(msg.clone()),
+            KalshiError::PaginationLimitReached { items, pages } => {
+                KalshiError::PaginationLimitReached {
+                    items: *items,
+                    pages: *pages,
+                }
+            }
+            #[cfg(feature = "websockets")]
+            KalshiError::WebsocketError(e) => KalshiError::WebsocketError(e.clone()),
+            KalshiError::Shared(e) => e.share(),
+            _ => KalshiError::Shared(self.clone()),
         }
     }
 }
 
-impl Error for KalshiError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// Error codes Kalshi reports in the body of non-success responses.
+///
+/// Codes this crate does not know yet are kept verbatim in [`ApiErrorCode::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    BadRequest,
+    InvalidParameters,
+    MissingParameters,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    MarketNotFound,
+    OrderNotFound,
+    MarketClosed,
+    MarketInactive,
+    ExchangeClosed,
+    TradingIsPaused,
+    InsufficientBalance,
+    OrderAlreadyExists,
+    TooManyRequests,
+    InternalServerError,
+    ServiceUnavailable,
+    Other(String),
+}
+
+impl ApiErrorCode {
+    /// Returns the code as Kalshi spells it.
+    pub fn as_str(&self) -> &str {
         match self {
-            KalshiError::RequestError(e) => Some(e),
-            KalshiError::UserInputError(_) => None,
-            KalshiError::InternalError(_) => None,
+            ApiErrorCode::BadRequest => "bad_request",
+            ApiErrorCode::InvalidParameters => "invalid_parameters",
+            ApiErrorCode::MissingParameters => "missing_parameters",
+            ApiErrorCode::Unauthorized => "unauthorized",
+            ApiErrorCode::Forbidden => "forbidden",
+            ApiErrorCode::NotFound => "not_found",
+            ApiErrorCode::MarketNotFound => "market_not_found",
+            ApiErrorCode::OrderNotFound => "order_not_found",
+            ApiErrorCode::MarketClosed => "market_closed",
+            ApiErrorCode::MarketInactive => "market_inactive",
+            ApiErrorCode::ExchangeClosed => "exchange_closed",
+            ApiErrorCode::TradingIsPaused => "trading_is_paused",
+            ApiErrorCode::InsufficientBalance => "insufficient_balance",
+            ApiErrorCode::OrderAlreadyExists => "order_already_exists",
+            ApiErrorCode::TooManyRequests => "too_many_requests",
+            ApiErrorCode::InternalServerError => "internal_server_error",
+            ApiErrorCode::ServiceUnavailable => "service_unavailable",
+            ApiErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "bad_request" => ApiErrorCode::BadRequest,
+            "invalid_parameters" => ApiErrorCode::InvalidParameters,
+            "missing_parameters" => ApiErrorCode::MissingParameters,
+            "unauthorized" => ApiErrorCode::Unauthorized,
+            "forbidden" => ApiErrorCode::Forbidden,
+            "not_found" => ApiErrorCode::NotFound,
+            "market_not_found" => ApiErrorCode::MarketNotFound,
+            "order_not_found" => ApiErrorCode::OrderNotFound,
+            "market_closed" => ApiErrorCode::MarketClosed,
+            "market_inactive" => ApiErrorCode::MarketInactive,
+            "exchange_closed" => ApiErrorCode::ExchangeClosed,
+            "trading_is_paused" => ApiErrorCode::TradingIsPaused,
+            "insufficient_balance" => ApiErrorCode::InsufficientBalance,
+            "order_already_exists" => ApiErrorCode::OrderAlreadyExists,
+            "too_many_requests" => ApiErrorCode::TooManyRequests,
+            "internal_server_error" => ApiErrorCode::InternalServerError,
+            "service_unavailable" => ApiErrorCode::ServiceUnavailable,
+            other => ApiErrorCode::Other(other.to_string()),
         }
     }
 }
 
+impl Display for ApiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl From<reqwest::Error> for KalshiError {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_decode() {
+        if err.is_timeout() {
+            KalshiError::Timeout(err.to_string())
+        } else if err.is_decode() {
             KalshiError::RequestError(RequestError::SerializationError(err))
-        } else if err.is_status() {
-            if let Some(status) = err.status() {
-                if status.is_client_error() {
-                    KalshiError::RequestError(RequestError::ClientError(err))
-                } else if status.is_server_error() {
-                    KalshiError::RequestError(RequestError::ServerError(err))
-                } else {
-                    KalshiError::RequestError(RequestError::ServerError(err))
-                }
-            } else {
-                KalshiError::RequestError(RequestError::ServerError(err))
-            }
-        } else if err.is_body() || err.is_timeout() {
-            KalshiError::RequestError(RequestError::ServerError(err))
+        } else if err.status().is_some_and(|status| status.is_client_error()) {
+            KalshiError::RequestError(RequestError::ClientError(err))
         } else {
             KalshiError::RequestError(RequestError::ServerError(err))
         }
     }
 }
 
+#[cfg(feature = "middleware")]
+impl From<reqwest_middleware::Error> for KalshiError {
+    fn from(err: reqwest_middleware::Error) -> Self {
+        match err {
+            reqwest_middleware::Error::Reqwest(e) => e.into(),
+            e => KalshiError::RequestError(RequestError::MiddlewareError(e)),
+        }
+    }
+}
+
+#[cfg(feature = "websockets")]
+impl From<crate::websockets::client::KalshiWebsocketError> for KalshiError {
+    fn from(err: crate::websockets::client::KalshiWebsocketError) -> Self {
+        KalshiError::WebsocketError(err)
+    }
+}
+
 /// Represents errors specific to HTTP requests within the Kalshi API client.
-#[derive(Debug)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum RequestError {
     /// Errors occurring during serialization or deserialization of request or response data.
-    SerializationError(reqwest::Error),
+    #[error("Serialization Error. You connected successfully but either: Your inputs to a request were incorrect or the exchange is closed!")]
+    SerializationError(#[source] reqwest::Error),
     /// Errors representing client-side request issues, such as bad requests or unauthorized access.
-    ClientError(reqwest::Error),
+    #[error("Client Request Error{}", status_suffix(.0))]
+    ClientError(#[source] reqwest::Error),
     /// Errors indicating server-side issues, like internal server errors or service unavailability.
-    ServerError(reqwest::Error),
+    #[error("Server Request Error{}", status_suffix(.0))]
+    ServerError(#[source] reqwest::Error),
     /// Errors occurring during URL parsing.
-    UrlParseError(url::ParseError),
-}
-
-impl fmt::Display for RequestError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            RequestError::SerializationError(e) => write!(f, "Serialization Error. You connected successfully but either: Your inputs to a request were incorrect or the exchange is closed! {}", e),
-            RequestError::ClientError(e) => {
-                if let Some(status) = e.status() {
-                    write!(f, "Client Request Error, Status code: {}", status)
-                } else {
-                    write!(f, "Client Request Error: {}", e)
-                }
-            },
-            RequestError::ServerError(e) => {
-                if let Some(status) = e.status() {
-                    write!(f, "Server Request Error, Status code: {}", status)
-                } else {
-                    write!(f, "Server Request Error: {}", e)
-                }
-            },
-            RequestError::UrlParseError(e) => write!(f, "URL Parse Error: {}", e),
-        }
-    }
+    #[error("URL Parse Error")]
+    UrlParseError(#[from] url::ParseError),
+    /// Errors raised by a middleware of a client set with
+    /// [`Kalshi::with_middleware_client`](crate::Kalshi::with_middleware_client).
+    #[cfg(feature = "middleware")]
+    #[error("Middleware Error")]
+    MiddlewareError(#[source] reqwest_middleware::Error),
 }
 
-impl Error for RequestError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            RequestError::ClientError(e) => Some(e),
-            RequestError::ServerError(e) => Some(e),
-            RequestError::SerializationError(e) => Some(e),
-            RequestError::UrlParseError(e) => Some(e),
-        }
-    }
+fn status_suffix(e: &reqwest::Error) -> String {
+    e.status()
+        .map(|status| format!(", Status code: {}", status))
+        .unwrap_or_default()
 }
@@ -65,45 +65,80 @@
 //! # }
 //! ```
 
-use std::sync::Arc;
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+pub use tokio_util::sync::CancellationToken;
 
 #[macro_use]
 mod utils;
 mod api_keys;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(not(target_arch = "wasm32"))]
+mod circuit_breaker;
+mod client_builder;
+mod clock;
 mod communications;
+#[cfg(not(target_arch = "wasm32"))]
+mod config;
+#[cfg(feature = "keyring")]
+mod credential_store;
 mod event;
 mod exchange;
 mod historical;
 mod http;
 mod kalshi_error;
 mod market;
+mod milestone;
 mod multivariate;
+mod pagination;
 mod portfolio;
+mod proxy;
+mod rate_limit;
+mod redact;
+mod retry;
+mod scheduler;
 mod series;
+mod signing;
+mod structured_target;
+mod transport;
 #[cfg(feature = "websockets")]
 mod websockets;
 
 pub use api_keys::*;
+#[cfg(feature = "cache")]
+pub use cache::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use circuit_breaker::*;
+pub use client_builder::*;
+pub use clock::*;
 pub use communications::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use config::*;
 pub use event::*;
 pub use exchange::*;
 pub use historical::*;
 pub use kalshi_error::*;
 pub use market::*;
+pub use milestone::*;
 pub use multivariate::*;
+pub use pagination::*;
 pub use portfolio::*;
+pub use proxy::*;
+pub use rate_limit::*;
+pub use redact::*;
+pub use retry::*;
+pub use scheduler::*;
 pub use series::*;
+pub use signing::*;
+pub use structured_target::*;
+pub use transport::*;
 
 #[cfg(feature = "websockets")]
 pub use websockets::*;
 
-use openssl::{
-    hash::MessageDigest,
-    pkey::{PKey, Private},
-    rsa::Padding,
-    sign::{RsaPssSaltlen, Signer},
-};
-
 /// The Kalshi struct is the core of the kalshi-crate. It acts as the interface
 /// between the user and the market, abstracting away the meat of requests
 /// by encapsulating authentication information and the client itself.
@@ -113,58 +148,202 @@ pub struct Kalshi {
     base_url: String,
     #[cfg(feature = "websockets")]
     ws_url: String,
+    /// Ping interval and liveness timeout for websocket connections.
+    #[cfg(feature = "websockets")]
+    ws_keepalive: websockets::client::KeepaliveConfig,
+    /// TLS and socket settings for websocket connections.
+    #[cfg(feature = "websockets")]
+    ws_connector: websockets::client::WsConnectorConfig,
+    /// Per-channel stale-feed timeouts for websocket subscriptions.
+    #[cfg(feature = "websockets")]
+    ws_watchdog: websockets::client::WatchdogConfig,
     /// Identifier for the authenticated user.
+    #[allow(dead_code)]
     member_id: Option<String>,
-    /// The HTTP client used for making requests.
-    client: reqwest::Client,
-    /// Stores the method of authentication and required keys.
-    auth: KalshiAuth,
+    /// Sends the signed HTTP requests.
+    transport: Arc<dyn HttpTransport>,
+    /// Deadline for each REST call, set with [`Kalshi::with_timeout`].
+    request_timeout: Option<Duration>,
+    /// Aborts requests and websocket connections, set with [`Kalshi::with_cancellation`].
+    cancellation: Option<CancellationToken>,
+    /// Called with the rate limit headers of every response, set with
+    /// [`Kalshi::on_rate_limit`].
+    rate_limit_hook: Option<RateLimitHook>,
+    /// Read and write token buckets shared between clones, set with
+    /// [`Kalshi::with_rate_limits`].
+    #[cfg(not(target_arch = "wasm32"))]
+    rate_limiter: Option<Arc<rate_limit::ClientRateLimiter>>,
+    /// Fails requests fast during outages, set with [`Kalshi::with_circuit_breaker`].
+    #[cfg(not(target_arch = "wasm32"))]
+    circuit_breaker: Option<Arc<circuit_breaker::CircuitBreaker>>,
+    /// Retries of transient failures, set with [`Kalshi::with_retry_policy`].
+    retry_policy: Option<RetryPolicy>,
+    /// Request slots shared between clones, set with [`Kalshi::with_concurrency_limit`].
+    scheduler: Option<Arc<scheduler::Scheduler>>,
+    /// Priority overriding request classification, set with
+    /// [`Kalshi::with_request_priority`].
+    priority: Option<RequestPriority>,
+    /// Delay before a slow GET is hedged, set with [`Kalshi::with_hedged_reads`].
+    hedge_delay: Option<Duration>,
+    /// Headers sent with every request, set with [`Kalshi::with_default_headers`].
+    default_headers: reqwest::header::HeaderMap,
+    /// Masks secrets in logged bodies, set with [`Kalshi::with_body_logging`].
+    redaction: redact::Redaction,
+    /// Exchange clock minus local clock in milliseconds, measured by [`Kalshi::sync_clock`].
+    clock_offset: clock::ClockOffset,
+    /// Stores the method of authentication and required keys, shared between clones so
+    /// [`Kalshi::rotate_credentials`] reaches all of them.
+    auth: Arc<RwLock<Arc<KalshiAuth>>>,
+    /// Metadata cache shared between clones, if enabled with [`Kalshi::with_cache`].
+    #[cfg(feature = "cache")]
+    cache: Option<Arc<cache::MetadataCache>>,
+    /// In-flight GET requests, if coalescing is enabled with [`Kalshi::with_request_coalescing`].
+    inflight: Option<Arc<http::InflightRequests>>,
 }
 
+#[derive(Clone)]
 pub enum KalshiAuth {
     ApiKey {
         /// UUID of the key from the Kalshi profile page.
         key_id: String,
         /// PEM formatted RSA private key.
         key: String,
-        /// The loaded private key.
-        p_key: Arc<PKey<Private>>,
-        /// The RSA signer used for authentication headers.
-        signer: Signer<'static>,
+        /// The loaded private key, used to sign authentication headers.
+        signer: Arc<RequestSigner>,
     },
 }
 
-impl Clone for KalshiAuth {
-    fn clone(&self) -> Self {
-        match self {
-            KalshiAuth::ApiKey { key_id, key, .. } => {
-                KalshiAuth::build_api_key(key_id.clone(), key.clone())
-            }
+/// Environment variable holding the API key id, read by [`KalshiAuth::from_env`].
+pub const KEY_ID_ENV: &str = "KALSHI_KEY_ID";
+/// Environment variable holding the PEM private key itself, read by [`KalshiAuth::from_env`].
+pub const PRIVATE_KEY_ENV: &str = "KALSHI_PRIVATE_KEY";
+/// Environment variable holding the path of a PEM private key file, read by
+/// [`KalshiAuth::from_env`].
+pub const PRIVATE_KEY_PATH_ENV: &str = "KALSHI_PRIVATE_KEY_PATH";
+/// Environment variable holding the passphrase of an encrypted private key, read by
+/// [`KalshiAuth::from_env`].
+pub const PRIVATE_KEY_PASSPHRASE_ENV: &str = "KALSHI_PRIVATE_KEY_PASSPHRASE";
+
+impl KalshiAuth {
+    /// Loads API key credentials, checking that the key id is set and the key parses.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_id` - ID of the api key from the Kalshi profile page.
+    /// * `key` - PEM formatted RSA private key from the Kalshi profile page, in PKCS#1
+    ///   (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`) form.
+    ///
+    /// Passphrase-protected keys are rejected; load them with
+    /// [`api_key_encrypted`](KalshiAuth::api_key_encrypted).
+    pub fn api_key(key_id: impl Into<String>, key: impl Into<String>) -> Result<Self, KalshiError> {
+        Self::load_api_key(key_id.into(), key.into(), None::<fn() -> String>)
+    }
+
+    /// Loads API key credentials from a passphrase-protected PEM private key.
+    pub fn api_key_encrypted(
+        key_id: impl Into<String>,
+        key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) -> Result<Self, KalshiError> {
+        let passphrase = passphrase.into();
+        Self::load_api_key(key_id.into(), key.into(), Some(move || passphrase))
+    }
+
+    /// Loads API key credentials, asking `passphrase` for the passphrase only if the
+    /// private key turns out to be encrypted, e.g. to prompt the user.
+    pub fn api_key_with_passphrase_callback<F>(
+        key_id: impl Into<String>,
+        key: impl Into<String>,
+        passphrase: F,
+    ) -> Result<Self, KalshiError>
+    where
+        F: FnOnce() -> String,
+    {
+        Self::load_api_key(key_id.into(), key.into(), Some(passphrase))
+    }
+
+    fn load_api_key<F>(
+        key_id: String,
+        key: String,
+        passphrase: Option<F>,
+    ) -> Result<Self, KalshiError>
+    where
+        F: FnOnce() -> String,
+    {
+        if key_id.trim().is_empty() {
+            return Err(KalshiError::AuthError("API key id is empty".to_string()));
+        }
+        let signer = if RequestSigner::is_encrypted_pem(&key) {
+            let passphrase = passphrase.ok_or_else(|| {
+                KalshiError::AuthError(
+                    "Private key is encrypted but no passphrase was given".to_string(),
+                )
+            })?;
+            RequestSigner::from_encrypted_pem(&key, passphrase().as_bytes())
+        } else {
+            RequestSigner::from_pem(&key)
         }
+        .map_err(|e| KalshiError::AuthError(format!("Unable to load private key: {}", e)))?;
+        Ok(KalshiAuth::ApiKey {
+            key_id,
+            key,
+            signer: Arc::new(signer),
+        })
     }
-}
 
-impl KalshiAuth {
-    fn build_api_key(key_id: String, key: String) -> Self {
-        let p_key = PKey::private_key_from_pem(key.as_bytes())
-            .expect("Unable to load private key from PEM string provided");
-        let mut signer = Signer::new(MessageDigest::sha256(), &p_key)
-            .expect("Unable to create signer from private key");
-        signer
-            .set_rsa_padding(Padding::PKCS1_PSS)
-            .expect("Unable to set RSA padding on signer");
-        signer
-            .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
-            .expect("Unable to set RSA PSS salt length for signer");
-        KalshiAuth::ApiKey {
+    /// Loads API key credentials with the private key read from a PEM file.
+    pub fn from_pem_file(
+        key_id: impl Into<String>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, KalshiError> {
+        Self::api_key(key_id, read_key_file(path.as_ref())?)
+    }
+
+    /// Loads API key credentials with a passphrase-protected private key read from a PEM
+    /// file.
+    pub fn from_encrypted_pem_file(
+        key_id: impl Into<String>,
+        path: impl AsRef<std::path::Path>,
+        passphrase: impl Into<String>,
+    ) -> Result<Self, KalshiError> {
+        Self::api_key_encrypted(key_id, read_key_file(path.as_ref())?, passphrase)
+    }
+
+    /// Loads API key credentials from the environment.
+    ///
+    /// The key id comes from `KALSHI_KEY_ID`. The private key comes from
+    /// `KALSHI_PRIVATE_KEY` if set, otherwise from the file named by
+    /// `KALSHI_PRIVATE_KEY_PATH`. An encrypted key is unlocked with
+    /// `KALSHI_PRIVATE_KEY_PASSPHRASE`.
+    pub fn from_env() -> Result<Self, KalshiError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let key_id = var(KEY_ID_ENV)
+            .ok_or_else(|| KalshiError::AuthError(format!("{} is not set", KEY_ID_ENV)))?;
+        let key = match (var(PRIVATE_KEY_ENV), var(PRIVATE_KEY_PATH_ENV)) {
+            (Some(key), _) => key,
+            (None, Some(path)) => read_key_file(path.as_ref())?,
+            (None, None) => {
+                return Err(KalshiError::AuthError(format!(
+                    "Neither {} nor {} is set",
+                    PRIVATE_KEY_ENV, PRIVATE_KEY_PATH_ENV
+                )))
+            }
+        };
+        Self::load_api_key(
             key_id,
             key,
-            p_key: Arc::new(p_key),
-            signer,
-        }
+            var(PRIVATE_KEY_PASSPHRASE_ENV).map(|p| move || p),
+        )
     }
 }
 
+fn read_key_file(path: &std::path::Path) -> Result<String, KalshiError> {
+    std::fs::read_to_string(path).map_err(|source| KalshiError::IoError {
+        context: format!("Unable to read private key {}", path.display()),
+        source,
+    })
+}
+
 impl Kalshi {
     /// Creates a new instance of Kalshi with the specified trading environment and API key details.
     ///
@@ -173,14 +352,75 @@ impl Kalshi {
     /// * `trading_env` - The trading environment to be used.
     /// * `key_id` - ID of the api key from the Kalshi profile page.
     /// * `key` - PEM formatted RSA private key from the Kalshi profile page.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_id` is empty or `key` is not a valid RSA private key. Use
+    /// [`try_new`](Kalshi::try_new) to handle that case.
     pub fn new(trading_env: TradingEnvironment, key_id: String, key: String) -> Self {
+        Self::try_new(trading_env, key_id, key)
+            .expect("Unable to load private key from PEM string provided")
+    }
+
+    /// Like [`new`](Kalshi::new), but returns [`KalshiError::AuthError`] instead of
+    /// panicking when the credentials are invalid.
+    pub fn try_new(
+        trading_env: TradingEnvironment,
+        key_id: String,
+        key: String,
+    ) -> Result<Self, KalshiError> {
+        Ok(Self::with_auth(
+            trading_env,
+            KalshiAuth::api_key(key_id, key)?,
+        ))
+    }
+
+    /// Creates a new instance of Kalshi from credentials that are already loaded, e.g.
+    /// with [`KalshiAuth::from_env`] or [`KalshiAuth::from_pem_file`].
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{Kalshi, KalshiAuth, TradingEnvironment};
+    /// # fn example() -> Result<Kalshi, kalshi::KalshiError> {
+    /// let kalshi = Kalshi::with_auth(TradingEnvironment::DemoMode, KalshiAuth::from_env()?);
+    /// # Ok(kalshi)
+    /// # }
+    /// ```
+    pub fn with_auth(trading_env: TradingEnvironment, auth: KalshiAuth) -> Self {
         Kalshi {
             base_url: utils::build_base_url(trading_env).to_string(),
             #[cfg(feature = "websockets")]
             ws_url: utils::build_ws_url(trading_env).to_string(),
+            #[cfg(feature = "websockets")]
+            ws_keepalive: Default::default(),
+            #[cfg(feature = "websockets")]
+            ws_connector: Default::default(),
+            #[cfg(feature = "websockets")]
+            ws_watchdog: Default::default(),
             member_id: None,
-            client: reqwest::Client::new(),
-            auth: KalshiAuth::build_api_key(key_id, key),
+            transport: Arc::new(
+                http::client_builder()
+                    .build()
+                    .expect("Unable to initialize the TLS backend"),
+            ),
+            request_timeout: None,
+            cancellation: None,
+            rate_limit_hook: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limiter: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            circuit_breaker: None,
+            retry_policy: None,
+            scheduler: None,
+            priority: None,
+            hedge_delay: None,
+            default_headers: Default::default(),
+            redaction: Default::default(),
+            clock_offset: Default::default(),
+            auth: Arc::new(RwLock::new(Arc::new(auth))),
+            #[cfg(feature = "cache")]
+            cache: None,
+            inflight: None,
         }
     }
 
@@ -189,6 +429,137 @@ impl Kalshi {
         Self::new(trading_env, key_id, key)
     }
 
+    /// Enables or disables coalescing of identical concurrent GET requests.
+    ///
+    /// When enabled, a GET issued while an identical one (same URL and query) is still
+    /// in flight waits for that request and shares its response instead of sending
+    /// another. This helps when many tasks hydrate the same market at once. Clones of
+    /// this client share the set of in-flight requests. Disabled by default.
+    pub fn with_request_coalescing(mut self, enabled: bool) -> Self {
+        self.inflight = enabled.then(|| Arc::new(http::InflightRequests::default()));
+        self
+    }
+
+    /// Sends requests through a preconfigured HTTP client.
+    ///
+    /// Lets applications share one connection pool between several clients and control
+    /// TLS, proxy and timeout settings centrally. Authentication headers are still added
+    /// to every request.
+    pub fn with_http_client(self, client: reqwest::Client) -> Self {
+        self.with_transport(client)
+    }
+
+    /// Sends requests through a client with a `reqwest-middleware` stack, e.g. for
+    /// retries, tracing or caching.
+    ///
+    /// Middleware errors are reported as [`RequestError::MiddlewareError`].
+    #[cfg(feature = "middleware")]
+    pub fn with_middleware_client(self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.with_transport(client)
+    }
+
+    /// Sets a deadline for each REST call, covering signing, sending and reading the
+    /// response, whatever transport is in use. Calls that run over fail with
+    /// [`KalshiError::Timeout`]. `None` disables the deadline, which is the default.
+    ///
+    /// Cloning the client is cheap, so a single call can get its own deadline. Not
+    /// enforced on wasm32, where the browser controls request lifetimes.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # async fn example(kalshi: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// let balance = kalshi
+    ///     .clone()
+    ///     .with_timeout(Some(Duration::from_millis(500)))
+    ///     .get_balance()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Aborts every request of this client, and every websocket connection opened from
+    /// it, as soon as `token` is cancelled.
+    ///
+    /// Shutting down a strategy then ends in-flight calls immediately with
+    /// [`KalshiError::Cancelled`] instead of waiting for their timeouts, and closes its
+    /// websocket connections as their `ShutdownHandle::trigger` would. Pass a [child token](CancellationToken::child_token) to cancel a single
+    /// call or task without affecting the rest.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{CancellationToken, Kalshi};
+    /// # async fn example(kalshi: Kalshi, shutdown: CancellationToken) -> Result<(), kalshi::KalshiError> {
+    /// let kalshi = kalshi.with_cancellation(shutdown.child_token());
+    /// let balance = kalshi.get_balance().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Replaces the credentials of this client and all of its clones.
+    ///
+    /// Lets long-running services rotate API keys without a restart. Requests already
+    /// signed finish with the old key; every request signed afterwards, including
+    /// websocket reconnects, uses the new one. Open websocket connections stay
+    /// authenticated with the key they connected with.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{Kalshi, KalshiAuth};
+    /// # fn example(kalshi: &Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// kalshi.rotate_credentials(KalshiAuth::from_pem_file("new-key-id", "new-key.pem")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rotate_credentials(&self, auth: KalshiAuth) {
+        *self.auth.write().unwrap() = Arc::new(auth);
+    }
+
+    /// Returns the credentials currently used to sign requests.
+    pub(crate) fn auth(&self) -> Arc<KalshiAuth> {
+        Arc::clone(&self.auth.read().unwrap())
+    }
+
+    /// Sets headers sent with every REST request and websocket handshake, e.g. a
+    /// `User-Agent` identifying the integration. Authentication headers always take
+    /// precedence. Replaces any default headers set before.
+    pub fn with_default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Hedges slow reads: a GET that has not answered within `delay` is sent a second
+    /// time, and whichever copy answers first is used. Smooths out tail latency on
+    /// market data reads during volatile periods, at the cost of extra requests
+    /// against the rate limit. `None` disables hedging, which is the default.
+    ///
+    /// Only GET requests are hedged; orders, cancels and every other call that changes
+    /// state are always sent exactly once. Not available on wasm32.
+    pub fn with_hedged_reads(mut self, delay: Option<Duration>) -> Self {
+        self.hedge_delay = delay;
+        self
+    }
+
+    /// Returns the deadline set with [`with_timeout`](Kalshi::with_timeout).
+    pub fn timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Sends requests through a custom [`HttpTransport`], e.g. one serving recorded
+    /// responses in tests.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
     /// Retrieves the currently set base url.
     pub fn get_base_url(&self) -> &str {
         &self.base_url
@@ -207,4 +578,4 @@ pub enum TradingEnvironment {
     LiveMarketMode,
     /// Legacy only markets.
     LegacyLiveMarketMode,
-}
\ No newline at end of file
+}
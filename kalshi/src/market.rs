@@ -1,25 +1,44 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use crate::{paginate, EventStatus, GetEventsParams, Paginated};
+use chrono::{DateTime, Utc};
+use futures_util::{future, stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
 impl Kalshi {
-    /// Retrieves information about a single event by its ticker.
+    /// Alias for `get_event` without nested markets.
     pub async fn get_single_event(&self, event_ticker: &str) -> Result<Event, KalshiError> {
-        let path = format!("/events/{}", event_ticker);
-        let url = self.build_url(&path)?;
-        let resp: SingleEventResponse = self.http_get(url).await?;
-        Ok(resp.event)
+        self.get_event(event_ticker, false).await
     }
 
-    /// Retrieves information about a single market by its ticker.
-    pub async fn get_single_market(&self, market_ticker: &str) -> Result<Market, KalshiError> {
+    /// Retrieves the full metadata of a single market by its ticker.
+    ///
+    /// Maps to GET /markets/{ticker}.
+    ///
+    /// Useful for hydrating a market when only its ticker is known, e.g. from a websocket
+    /// message, without first looking up its event.
+    pub async fn get_market(&self, market_ticker: &str) -> Result<Market, KalshiError> {
         let path = format!("/markets/{}", market_ticker);
         let url = self.build_url(&path)?;
         let resp: SingleMarketResponse = self.http_get(url).await?;
         Ok(resp.market)
     }
 
+    /// Alias for `get_market`.
+    pub async fn get_single_market(&self, market_ticker: &str) -> Result<Market, KalshiError> {
+        self.get_market(market_ticker).await
+    }
+
     /// Retrieves multiple markets with various filters.
+    ///
+    /// Thin wrapper around [`get_markets`](Kalshi::get_markets): `status` is parsed into a
+    /// [`MarketStatus`] and `tickers` is split on commas.
+    #[deprecated(note = "use `get_markets` with `GetMarketsParams` instead")]
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_multiple_markets(
         &self,
         limit: Option<i64>,
@@ -31,19 +50,280 @@ impl Kalshi {
         min_close_ts: Option<i64>,
         tickers: Option<String>,
     ) -> Result<(Vec<Market>, Option<String>), KalshiError> {
-        let mut params = Vec::new();
-        add_param!(params, "limit", limit);
-        add_param!(params, "cursor", cursor);
-        add_param!(params, "status", status);
-        add_param!(params, "series_ticker", series_ticker);
-        add_param!(params, "event_ticker", event_ticker);
-        add_param!(params, "max_close_ts", max_close_ts);
-        add_param!(params, "min_close_ts", min_close_ts);
-        add_param!(params, "tickers", tickers);
+        let status = status
+            .map(|status| {
+                serde_json::from_value(serde_json::Value::String(status.clone())).map_err(|_| {
+                    KalshiError::UserInputError(format!("Unknown market status: {}", status))
+                })
+            })
+            .transpose()?;
+        let tickers = tickers
+            .map(|tickers| {
+                tickers
+                    .split(',')
+                    .filter(|ticker| !ticker.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.get_markets(&GetMarketsParams {
+            limit,
+            cursor,
+            event_ticker,
+            series_ticker,
+            status,
+            tickers,
+            min_close_ts,
+            max_close_ts,
+            ..Default::default()
+        })
+        .await
+    }
 
-        let url = self.build_url_with_params("/markets", params)?;
+    /// Retrieves a page of markets matching the given filters.
+    ///
+    /// Maps to GET /markets.
+    ///
+    /// This is the main market discovery endpoint. Only one status filter can be applied
+    /// at a time, and each timestamp filter pair is only compatible with certain statuses
+    /// (e.g. `min_close_ts`/`max_close_ts` with `closed` or no status); see
+    /// [`GetMarketsParams`] for details.
+    ///
+    /// # Arguments
+    /// * `params` - Filters and pagination for the request; use `Default::default()` for none.
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Market>, Option<String>))`: A page of markets and the cursor for the next page, if any.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::{GetMarketsParams, MarketStatus};
+    ///
+    /// let (markets, cursor) = k
+    ///     .get_markets(&GetMarketsParams {
+    ///         series_ticker: Some("KXHIGHNY".to_string()),
+    ///         status: Some(MarketStatus::Open),
+    ///         limit: Some(200),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_markets(
+        &self,
+        params: &GetMarketsParams,
+    ) -> Result<(Vec<Market>, Option<String>), KalshiError> {
+        let mut query = Vec::new();
+        add_param!(query, "limit", params.limit);
+        add_param!(query, "cursor", params.cursor.as_ref());
+        add_param!(query, "event_ticker", params.event_ticker.as_ref());
+        add_param!(query, "series_ticker", params.series_ticker.as_ref());
+        add_param!(query, "status", params.status);
+        if !params.tickers.is_empty() {
+            query.push(("tickers", params.tickers.join(",")));
+        }
+        add_param!(query, "min_close_ts", params.min_close_ts);
+        add_param!(query, "max_close_ts", params.max_close_ts);
+        add_param!(query, "min_created_ts", params.min_created_ts);
+        add_param!(query, "max_created_ts", params.max_created_ts);
+        add_param!(query, "min_settled_ts", params.min_settled_ts);
+        add_param!(query, "max_settled_ts", params.max_settled_ts);
+        add_param!(query, "min_updated_ts", params.min_updated_ts);
+        add_param!(query, "mve_filter", params.mve_filter.as_ref());
+
+        let url = self.build_url_with_params("/markets", query)?;
         let resp: PublicMarketsResponse = self.http_get(url).await?;
-        Ok((resp.markets, resp.cursor))
+        let cursor = resp.cursor.filter(|c| !c.is_empty());
+        Ok((resp.markets, cursor))
+    }
+
+    /// Walks every page of [`get_markets`](Kalshi::get_markets) and returns the markets
+    /// for which `predicate` returns true.
+    ///
+    /// Server-side filters in `params` (series, event, status, timestamps) are applied
+    /// first, so narrowing them keeps the number of pages fetched down. Any `cursor` in
+    /// `params` is used as the starting point.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::{GetMarketsParams, MarketStatus};
+    ///
+    /// let liquid = k
+    ///     .find_markets(
+    ///         GetMarketsParams {
+    ///             series_ticker: Some("KXHIGHNY".to_string()),
+    ///             status: Some(MarketStatus::Open),
+    ///             ..Default::default()
+    ///         },
+    ///         |m| m.volume_24h > 1_000,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_markets<F>(
+        &self,
+        params: GetMarketsParams,
+        mut predicate: F,
+    ) -> Result<Vec<Market>, KalshiError>
+    where
+        F: FnMut(&Market) -> bool,
+    {
+        self.stream_markets(params)
+            .try_filter(|m| future::ready(predicate(m)))
+            .try_collect()
+            .await
+    }
+
+    /// Streams every market matching `params`, fetching further pages as needed.
+    ///
+    /// Any `cursor` in `params` is used as the starting point; `limit` sets the page size.
+    pub fn stream_markets(&self, params: GetMarketsParams) -> Paginated<'_, Market> {
+        paginate(params.cursor.clone(), move |cursor| {
+            let params = GetMarketsParams {
+                cursor,
+                ..params.clone()
+            };
+            async move { self.get_markets(&params).await }
+        })
+    }
+
+    /// Streams every trade matching the filters, fetching further pages as needed.
+    ///
+    /// # Arguments
+    /// * `ticker` - Optional market ticker filter.
+    /// * `min_ts` - Optional filter for trades after this Unix timestamp.
+    /// * `max_ts` - Optional filter for trades before this Unix timestamp.
+    /// * `limit` - Optional page size.
+    pub fn stream_trades(
+        &self,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+        limit: Option<i64>,
+    ) -> Paginated<'_, Trade> {
+        paginate(None, move |cursor| {
+            self.get_trades(ticker.clone(), min_ts, max_ts, limit, cursor)
+        })
+    }
+
+    /// Finds open markets across every series matching the given categories and tags.
+    ///
+    /// Series are resolved with [`get_series_list`](Kalshi::get_series_list), then each
+    /// series' open events are paged through with their markets nested, and the markets
+    /// are flattened into a single list. Series are searched one after another, so broad
+    /// queries (no categories or tags) can take many requests.
+    ///
+    /// # Arguments
+    /// * `categories` - Series categories to include; empty means all categories.
+    /// * `tags` - Series tags to include; empty means no tag filter.
+    /// * `close_before` - If set, only markets closing at or before this time are returned.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// // All inflation markets closing within the next week.
+    /// let week = chrono::Utc::now() + chrono::Duration::days(7);
+    /// let markets = k
+    ///     .search_markets(vec![], vec!["Inflation".to_string()], Some(week))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_markets(
+        &self,
+        categories: Vec<String>,
+        tags: Vec<String>,
+        close_before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Market>, KalshiError> {
+        let series = self
+            .get_series_list(categories, tags, None, None, None)
+            .await?;
+
+        let mut markets = Vec::new();
+        for series in series {
+            let mut events = self.stream_events(GetEventsParams {
+                series_ticker: Some(series.ticker),
+                status: Some(EventStatus::Open),
+                with_nested_markets: true,
+                ..Default::default()
+            });
+            while let Some(event) = events.try_next().await? {
+                markets.extend(
+                    event
+                        .markets
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|m| m.status == "active")
+                        .filter(|m| match close_before {
+                            Some(limit) => DateTime::parse_from_rfc3339(&m.close_time)
+                                .is_ok_and(|close| close <= limit),
+                            None => true,
+                        }),
+                );
+            }
+        }
+        Ok(markets)
+    }
+
+    /// Fetches many markets by ticker, splitting the list into batches of
+    /// [`MAX_TICKERS_PER_REQUEST`] and issuing up to `concurrency` batch requests at once.
+    ///
+    /// Duplicate tickers are requested once. Tickers the API does not return are reported
+    /// in [`MarketsByTickers::missing`] rather than failing the whole call.
+    ///
+    /// # Arguments
+    /// * `tickers` - Market tickers to fetch.
+    /// * `concurrency` - Maximum number of batch requests in flight; values below 1 are treated as 1.
+    ///
+    /// # Returns
+    /// - `Ok(MarketsByTickers)`: The markets keyed by ticker, plus the tickers that were not found.
+    /// - `Err(KalshiError)`: If any batch request fails.
+    pub async fn get_markets_by_tickers(
+        &self,
+        tickers: &[String],
+        concurrency: usize,
+    ) -> Result<MarketsByTickers, KalshiError> {
+        let mut seen = HashSet::new();
+        let requested: Vec<String> = tickers
+            .iter()
+            .filter(|t| seen.insert(t.as_str()))
+            .cloned()
+            .collect();
+
+        let batches: Vec<Vec<Market>> = stream::iter(requested.chunks(MAX_TICKERS_PER_REQUEST))
+            .map(|chunk| async move {
+                let params = GetMarketsParams {
+                    tickers: chunk.to_vec(),
+                    limit: Some(chunk.len() as i64),
+                    ..Default::default()
+                };
+                self.get_markets(&params).await.map(|(markets, _)| markets)
+            })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut markets: HashMap<String, Market> = batches
+            .into_iter()
+            .flatten()
+            .map(|m| (m.ticker.clone(), m))
+            .collect();
+        let mut result = MarketsByTickers::default();
+        for ticker in requested {
+            match markets.remove(&ticker) {
+                Some(market) => {
+                    result.order.push(ticker.clone());
+                    result.markets.insert(ticker, market);
+                }
+                None => result.missing.push(ticker),
+            }
+        }
+        Ok(result)
     }
 
     /// Retrieves multiple events with various filters.
@@ -65,22 +345,33 @@ impl Kalshi {
         Ok((resp.events, resp.cursor))
     }
 
-    /// Retrieves series information by ticker.
-    pub async fn get_series(&self, series_ticker: &str) -> Result<Series, KalshiError> {
-        let path = format!("/series/{}", series_ticker);
-        let url = self.build_url(&path)?;
-        let resp: SeriesResponse = self.http_get(url).await?;
-        Ok(resp.series)
-    }
-
-    /// Retrieves the orderbook for a specific market.
-    pub async fn get_market_orderbook(&self, market_ticker: &str, depth: Option<i32>) -> Result<Orderbook, KalshiError> {
+    /// Retrieves the resting bids on both sides of a market's orderbook.
+    ///
+    /// Maps to GET /markets/{ticker}/orderbook.
+    ///
+    /// Only bids are returned: a yes bid at X cents is equivalent to a no ask at 100 - X.
+    /// Levels are ordered from best to worst and use the same representation as the
+    /// websocket orderbook snapshot, so a REST book can seed or cross-check a local book.
+    ///
+    /// # Arguments
+    /// * `market_ticker` - Ticker of the market.
+    /// * `depth` - Optional number of levels per side (1-100); `None` or 0 returns every level.
+    pub async fn get_market_orderbook(
+        &self,
+        market_ticker: &str,
+        depth: Option<i32>,
+    ) -> Result<Orderbook, KalshiError> {
         let path = format!("/markets/{}/orderbook", market_ticker);
         let mut params = Vec::new();
         add_param!(params, "depth", depth);
         let url = self.build_url_with_params(&path, params)?;
         let resp: OrderBookResponse = self.http_get(url).await?;
-        Ok(resp.orderbook)
+        let mut orderbook = resp.orderbook;
+        if let Some(fp) = resp.orderbook_fp {
+            orderbook.yes_dollars_fp = fp.yes_dollars;
+            orderbook.no_dollars_fp = fp.no_dollars;
+        }
+        Ok(orderbook)
     }
 
     /// Retrieves market price history (candlesticks).
@@ -102,31 +393,47 @@ impl Kalshi {
         Ok((resp.ticker, resp.history))
     }
 
-    /// Retrieves public trades for one or more markets.
+    /// Retrieves public trades, newest first, optionally filtered by market and time range.
+    ///
+    /// Maps to GET /markets/trades.
+    ///
+    /// The returned [`Trade`] records carry the same price and size fields as the
+    /// websocket `trade` channel, so REST backfills and live prints can be merged.
+    ///
+    /// # Arguments
+    /// * `ticker` - Optional market ticker; all markets when omitted.
+    /// * `min_ts` - Optional Unix timestamp; only trades after it are returned.
+    /// * `max_ts` - Optional Unix timestamp; only trades before it are returned.
+    /// * `limit` - Optional number of results per page (1-1000, API default 100).
+    /// * `cursor` - Optional pagination cursor from a previous response.
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Trade>, Option<String>))`: A page of trades and the cursor for the next page, if any.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
     pub async fn get_trades(
         &self,
-        tickers: Option<String>,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
         limit: Option<i64>,
         cursor: Option<String>,
     ) -> Result<(Vec<Trade>, Option<String>), KalshiError> {
         let mut params = Vec::new();
-        add_param!(params, "tickers", tickers);
+        add_param!(params, "ticker", ticker);
+        add_param!(params, "min_ts", min_ts);
+        add_param!(params, "max_ts", max_ts);
         add_param!(params, "limit", limit);
         add_param!(params, "cursor", cursor);
 
         let url = self.build_url_with_params("/markets/trades", params)?;
         let resp: PublicTradesResponse = self.http_get(url).await?;
-        Ok((resp.trades, resp.cursor))
+        let cursor = resp.cursor.filter(|c| !c.is_empty());
+        Ok((resp.trades, cursor))
     }
 }
 
 // Structs for API responses
 
-#[derive(Debug, Deserialize)]
-struct SingleEventResponse {
-    pub event: Event,
-}
-
 #[derive(Debug, Deserialize)]
 struct SingleMarketResponse {
     pub market: Market,
@@ -145,13 +452,15 @@ struct PublicEventsResponse {
 }
 
 #[derive(Debug, Deserialize)]
-struct SeriesResponse {
-    pub series: Series,
+struct OrderBookResponse {
+    pub orderbook: Orderbook,
+    pub orderbook_fp: Option<OrderbookFp>,
 }
 
 #[derive(Debug, Deserialize)]
-struct OrderBookResponse {
-    pub orderbook: Orderbook,
+struct OrderbookFp {
+    pub yes_dollars: Option<Vec<PriceLevelDollarsFp>>,
+    pub no_dollars: Option<Vec<PriceLevelDollarsFp>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -284,6 +593,10 @@ pub struct Event {
     pub markets: Option<Vec<Market>>,
     pub strike_date: Option<String>,
     pub strike_period: Option<String>,
+    pub collateral_return_type: Option<String>,
+    pub available_on_brokers: Option<bool>,
+    pub product_metadata: Option<serde_json::Value>,
+    pub last_updated_ts: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -300,6 +613,9 @@ pub struct Series {
     pub tags: Vec<String>,
     pub ticker: String,
     pub title: String,
+    pub volume: Option<i64>,
+    pub volume_fp: Option<String>,
+    pub last_updated_ts: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -308,12 +624,28 @@ pub struct SettlementSource {
     pub name: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+/// A price level as `(price in cents, contracts)`.
+pub type PriceLevel = (u32, u32);
+
+/// A price level as `(price in dollars, contracts)`, e.g. `("0.1500", 100)`.
+pub type PriceLevelDollars = (String, u32);
+
+/// A price level as `(price in dollars, fixed-point contracts)`, e.g. `("0.1500", "100.00")`.
+pub type PriceLevelDollarsFp = (String, String);
+
+/// Resting bids for both sides of a market, best price first.
+///
+/// A side is `None` when it has no resting orders.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Orderbook {
-    pub yes: Option<Vec<(u32, i32)>>,
-    pub no: Option<Vec<(u32, i32)>>,
-    pub yes_dollars: Option<Vec<(String, i32)>>,
-    pub no_dollars: Option<Vec<(String, i32)>>,
+    pub yes: Option<Vec<PriceLevel>>,
+    pub yes_dollars: Option<Vec<PriceLevelDollars>>,
+    #[serde(default)]
+    pub yes_dollars_fp: Option<Vec<PriceLevelDollarsFp>>,
+    pub no: Option<Vec<PriceLevel>>,
+    pub no_dollars: Option<Vec<PriceLevelDollars>>,
+    #[serde(default)]
+    pub no_dollars_fp: Option<Vec<PriceLevelDollarsFp>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -328,21 +660,104 @@ pub struct Snapshot {
     pub ts: u64,
 }
 
+/// A public trade print.
+///
+/// Trades from the websocket `trade` channel convert into it with `From`, so REST
+/// backfills and live prints can share one code path.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Trade {
     pub trade_id: String,
-    pub taker_side: String,
     pub ticker: String,
+    pub taker_side: crate::Side,
     pub count: u32,
+    pub count_fp: Option<String>,
+    /// Yes price in cents.
     pub yes_price: u32,
+    pub yes_price_dollars: Option<String>,
+    /// No price in cents.
     pub no_price: u32,
-    pub created_time: String,
+    pub no_price_dollars: Option<String>,
+    pub created_time: Option<String>,
+}
+
+/// Maximum number of tickers sent in a single request by [`Kalshi::get_markets_by_tickers`].
+pub const MAX_TICKERS_PER_REQUEST: usize = 100;
+
+/// Result of [`Kalshi::get_markets_by_tickers`].
+#[derive(Debug, Clone, Default)]
+pub struct MarketsByTickers {
+    /// Markets that were found, keyed by ticker.
+    pub markets: HashMap<String, Market>,
+    /// Tickers of the found markets, in the order they were requested.
+    pub order: Vec<String>,
+    /// Requested tickers the API did not return, in the order they were requested.
+    pub missing: Vec<String>,
+}
+
+impl MarketsByTickers {
+    /// Returns the market for `ticker`, if it was found.
+    pub fn get(&self, ticker: &str) -> Option<&Market> {
+        self.markets.get(ticker)
+    }
+
+    /// Iterates over the found markets in request order.
+    pub fn iter(&self) -> impl Iterator<Item = &Market> {
+        self.order.iter().filter_map(|t| self.markets.get(t))
+    }
+}
+
+/// Filters and pagination for [`Kalshi::get_markets`].
+///
+/// Timestamps are Unix seconds. The API treats the timestamp filter pairs as mutually
+/// exclusive and restricts which `status` each pair may be combined with:
+///
+/// | Timestamp filters | Compatible statuses |
+/// |-------------------|---------------------|
+/// | `min_created_ts`, `max_created_ts` | `unopened`, `open`, none |
+/// | `min_close_ts`, `max_close_ts` | `closed`, none |
+/// | `min_settled_ts`, `max_settled_ts` | `settled`, none |
+/// | `min_updated_ts` | none |
+#[derive(Debug, Clone, Default)]
+pub struct GetMarketsParams {
+    /// Number of results per page (1-1000, API default 100).
+    pub limit: Option<i64>,
+    /// Cursor returned by a previous page.
+    pub cursor: Option<String>,
+    pub event_ticker: Option<String>,
+    pub series_ticker: Option<String>,
+    pub status: Option<MarketStatus>,
+    /// Specific market tickers to retrieve; empty means no ticker filter.
+    pub tickers: Vec<String>,
+    pub min_close_ts: Option<i64>,
+    pub max_close_ts: Option<i64>,
+    pub min_created_ts: Option<i64>,
+    pub max_created_ts: Option<i64>,
+    pub min_settled_ts: Option<i64>,
+    pub max_settled_ts: Option<i64>,
+    pub min_updated_ts: Option<i64>,
+    /// `"only"` to return only multivariate (combo) markets, `"exclude"` to leave them out.
+    pub mve_filter: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum MarketStatus {
+    Unopened,
     Open,
+    Paused,
     Closed,
     Settled,
-}
\ No newline at end of file
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            MarketStatus::Unopened => "unopened",
+            MarketStatus::Open => "open",
+            MarketStatus::Paused => "paused",
+            MarketStatus::Closed => "closed",
+            MarketStatus::Settled => "settled",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,177 @@
+use super::Kalshi;
+use crate::kalshi_error::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl Kalshi {
+    /// Retrieves a single milestone by its ID.
+    ///
+    /// Maps to GET /milestones/{milestone_id}
+    pub async fn get_milestone(&self, milestone_id: &str) -> Result<Milestone, KalshiError> {
+        let path = format!("/milestones/{}", milestone_id);
+        let url = self.build_url(&path)?;
+        let resp: GetMilestoneResponse = self.http_get(url).await?;
+        Ok(resp.milestone)
+    }
+
+    /// Retrieves milestones with optional filters.
+    ///
+    /// Maps to GET /milestones
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Milestone>, Option<String>))`: A page of milestones and the cursor for the next page.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    pub async fn get_milestones(
+        &self,
+        params: &GetMilestonesParams,
+    ) -> Result<(Vec<Milestone>, Option<String>), KalshiError> {
+        let mut query = Vec::new();
+        add_param!(
+            query,
+            "limit",
+            Some(params.limit.unwrap_or(DEFAULT_MILESTONES_LIMIT))
+        );
+        add_param!(query, "cursor", params.cursor.as_ref());
+        add_param!(
+            query,
+            "minimum_start_date",
+            params.minimum_start_date.as_ref()
+        );
+        add_param!(query, "category", params.category.as_ref());
+        add_param!(query, "competition", params.competition.as_ref());
+        add_param!(query, "source_id", params.source_id.as_ref());
+        add_param!(query, "type", params.type_.as_ref());
+        add_param!(
+            query,
+            "related_event_ticker",
+            params.related_event_ticker.as_ref()
+        );
+        add_param!(query, "min_updated_ts", params.min_updated_ts);
+
+        let url = self.build_url_with_params("/milestones", query)?;
+        let resp: GetMilestonesResponse = self.http_get(url).await?;
+        Ok((resp.milestones, resp.cursor))
+    }
+
+    /// Retrieves live data for a specific milestone.
+    ///
+    /// Maps to GET /live_data/{type}/milestone/{milestone_id}
+    pub async fn get_live_data(
+        &self,
+        type_: &str,
+        milestone_id: &str,
+    ) -> Result<LiveData, KalshiError> {
+        let path = format!("/live_data/{}/milestone/{}", type_, milestone_id);
+        let url = self.build_url(&path)?;
+        let resp: GetLiveDataResponse = self.http_get(url).await?;
+        Ok(resp.live_data)
+    }
+
+    /// Retrieves live data for multiple milestones (up to 100 per call).
+    ///
+    /// Maps to GET /live_data/batch
+    pub async fn get_live_datas(
+        &self,
+        milestone_ids: Vec<String>,
+    ) -> Result<Vec<LiveData>, KalshiError> {
+        let mut params = Vec::new();
+        for id in milestone_ids {
+            params.push(("milestone_ids", id));
+        }
+        let url = self.build_url_with_params("/live_data/batch", params)?;
+        let resp: GetLiveDatasResponse = self.http_get(url).await?;
+        Ok(resp.live_datas)
+    }
+}
+
+// PRIVATE RESPONSES
+// -----------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct GetMilestoneResponse {
+    pub milestone: Milestone,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetMilestonesResponse {
+    pub milestones: Vec<Milestone>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetLiveDataResponse {
+    pub live_data: LiveData,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetLiveDatasResponse {
+    pub live_datas: Vec<LiveData>,
+}
+
+// PUBLIC STRUCTS
+// -----------------------------------------------
+
+/// Page size sent when [`GetMilestonesParams::limit`] is unset, since the API requires one.
+const DEFAULT_MILESTONES_LIMIT: i32 = 100;
+
+/// Filters and pagination for [`Kalshi::get_milestones`].
+#[derive(Debug, Clone, Default)]
+pub struct GetMilestonesParams {
+    /// Number of results per page (1-500). Defaults to 100.
+    pub limit: Option<i32>,
+    /// Cursor returned by a previous page.
+    pub cursor: Option<String>,
+    /// RFC3339 timestamp; only milestones starting on or after it are returned.
+    pub minimum_start_date: Option<String>,
+    pub category: Option<String>,
+    pub competition: Option<String>,
+    pub source_id: Option<String>,
+    /// Milestone type.
+    pub type_: Option<String>,
+    /// Only milestones related to this event.
+    pub related_event_ticker: Option<String>,
+    /// Unix timestamp; only milestones updated after it are returned.
+    pub min_updated_ts: Option<i64>,
+}
+
+/// A real-world occurrence (a game, a data release, a vote) that events settle against.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Milestone {
+    /// Unique identifier for the milestone.
+    pub id: String,
+    /// Category of the milestone.
+    pub category: String,
+    /// Type of the milestone.
+    pub r#type: String,
+    /// Start date (RFC3339).
+    pub start_date: String,
+    /// End date (RFC3339), if any.
+    pub end_date: Option<String>,
+    /// Event tickers related to this milestone.
+    pub related_event_tickers: Vec<String>,
+    /// Title of the milestone.
+    pub title: String,
+    /// Notification message for the milestone.
+    pub notification_message: String,
+    /// Source id of the milestone, if available.
+    pub source_id: Option<String>,
+    /// Source ids keyed by provider, if available.
+    pub source_ids: Option<HashMap<String, String>>,
+    /// Free-form details about the milestone.
+    pub details: serde_json::Value,
+    /// Event tickers directly related to the outcome of this milestone.
+    pub primary_event_tickers: Vec<String>,
+    /// Last time this milestone was updated (RFC3339).
+    pub last_updated_ts: String,
+}
+
+/// Live data attached to a milestone, such as a game score.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LiveData {
+    /// Type of live data.
+    pub r#type: String,
+    /// Free-form live data details.
+    pub details: serde_json::Value,
+    /// Milestone the data belongs to.
+    pub milestone_id: String,
+}
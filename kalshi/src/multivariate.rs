@@ -47,12 +47,32 @@ impl Kalshi {
         collection_ticker: &str,
         selected_markets: Vec<crate::market::MveSelectedLeg>,
     ) -> Result<MultivariateMarketLookupResponse, KalshiError> {
-        let path = format!("/multivariate_event_collections/{}/lookup", collection_ticker);
+        let path = format!(
+            "/multivariate_event_collections/{}/lookup",
+            collection_ticker
+        );
         let url = self.build_url(&path)?;
         let payload = MultivariateMarketLookupRequest { selected_markets };
         self.http_put(url, &payload).await
     }
 
+    /// Looks up the market for a combination built with [`MultivariateLookup`].
+    ///
+    /// Subscribers to the `multivariate` websocket channel receive a
+    /// `MultivariateLookup` notification for the resulting market.
+    pub async fn lookup_multivariate(
+        &self,
+        lookup: &MultivariateLookup,
+    ) -> Result<MultivariateMarketLookupResponse, KalshiError> {
+        if lookup.selected_markets.is_empty() {
+            return Err(KalshiError::UserInputError(
+                "A multivariate lookup needs at least one selected market".to_string(),
+            ));
+        }
+        self.lookup_multivariate_market(&lookup.collection_ticker, lookup.selected_markets.clone())
+            .await
+    }
+
     /// Creates a market in a multivariate event collection.
     ///
     /// Maps to POST /multivariate_event_collections/{collection_ticker}
@@ -71,17 +91,27 @@ impl Kalshi {
         self.http_post(url, &payload).await
     }
 
-    /// Retrieves lookup history for a multivariate event collection.
+    /// Retrieves the combinations in a multivariate event collection that were recently looked up.
     ///
     /// Maps to GET /multivariate_event_collections/{collection_ticker}/lookup
+    ///
+    /// The endpoint is not cursor-paginated: it returns every lookup point inside the
+    /// requested window in a single response, so the window itself bounds the result size.
+    ///
+    /// # Arguments
+    /// * `collection_ticker` - Ticker of the collection to inspect.
+    /// * `lookback` - How far back to look for lookups.
     pub async fn get_multivariate_lookup_history(
         &self,
         collection_ticker: &str,
-        lookback_seconds: i32,
+        lookback: LookbackWindow,
     ) -> Result<Vec<MultivariateLookupPoint>, KalshiError> {
-        let path = format!("/multivariate_event_collections/{}/lookup", collection_ticker);
+        let path = format!(
+            "/multivariate_event_collections/{}/lookup",
+            collection_ticker
+        );
         let mut params = Vec::new();
-        add_param!(params, "lookback_seconds", Some(lookback_seconds));
+        add_param!(params, "lookback_seconds", Some(lookback.as_seconds()));
 
         let url = self.build_url_with_params(&path, params)?;
         let resp: GetMultivariateEventCollectionLookupHistoryResponse = self.http_get(url).await?;
@@ -166,4 +196,80 @@ pub struct MultivariateLookupPoint {
     pub market_ticker: String,
     pub selected_markets: Vec<crate::market::MveSelectedLeg>,
     pub last_queried_ts: String,
-}
\ No newline at end of file
+}
+
+/// A combination of market/side selections to look up in a multivariate event collection.
+///
+/// # Example
+/// ```
+/// # use kalshi::{MultivariateLookup, Side};
+/// let lookup = MultivariateLookup::new("KXMVECOLLECTION")
+///     .leg("KXEVENT-A", "KXEVENT-A-YES", Side::Yes)
+///     .leg("KXEVENT-B", "KXEVENT-B-NO", Side::No);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultivariateLookup {
+    collection_ticker: String,
+    selected_markets: Vec<crate::market::MveSelectedLeg>,
+}
+
+impl MultivariateLookup {
+    /// Starts an empty lookup in the given collection.
+    pub fn new(collection_ticker: impl Into<String>) -> Self {
+        MultivariateLookup {
+            collection_ticker: collection_ticker.into(),
+            selected_markets: Vec::new(),
+        }
+    }
+
+    /// Adds a selected market and side to the combination.
+    pub fn leg(
+        mut self,
+        event_ticker: impl Into<String>,
+        market_ticker: impl Into<String>,
+        side: crate::Side,
+    ) -> Self {
+        let side = match side {
+            crate::Side::Yes => "yes",
+            crate::Side::No => "no",
+        };
+        self.selected_markets.push(crate::market::MveSelectedLeg {
+            event_ticker: Some(event_ticker.into()),
+            market_ticker: Some(market_ticker.into()),
+            side: Some(side.to_string()),
+            yes_settlement_value_dollars: None,
+        });
+        self
+    }
+
+    /// Returns the collection the lookup targets.
+    pub fn collection_ticker(&self) -> &str {
+        &self.collection_ticker
+    }
+
+    /// Returns the selected legs, in the order they were added.
+    pub fn selected_markets(&self) -> &[crate::market::MveSelectedLeg] {
+        &self.selected_markets
+    }
+}
+
+/// Lookback windows accepted by the collection lookup history endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookbackWindow {
+    TenSeconds,
+    OneMinute,
+    FiveMinutes,
+    OneHour,
+}
+
+impl LookbackWindow {
+    /// Returns the window length in seconds, as expected by the API.
+    pub const fn as_seconds(&self) -> i32 {
+        match self {
+            LookbackWindow::TenSeconds => 10,
+            LookbackWindow::OneMinute => 60,
+            LookbackWindow::FiveMinutes => 300,
+            LookbackWindow::OneHour => 3600,
+        }
+    }
+}
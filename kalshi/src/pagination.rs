@@ -0,0 +1,278 @@
+use crate::{kalshi_error::*, transport::MaybeSend};
+#[cfg(not(target_arch = "wasm32"))]
+use futures_util::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures_util::stream::LocalBoxStream as BoxStream;
+use futures_util::{
+    future::Future,
+    ready,
+    stream::{self, Stream, StreamExt, TryStreamExt},
+};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// PAGINATION
+// -----------------------------------------------
+
+/// A stream of items from a cursor-paginated endpoint.
+///
+/// By default pages are requested lazily: the next page is only fetched once every item
+/// of the current one has been yielded. [`with_prefetch`](Paginated::with_prefetch)
+/// instead requests later pages while earlier ones are being yielded.
+/// The stream ends after the last page, or right after the first error.
+///
+/// Created with [`paginate`] or one of the `stream_*` methods on [`Kalshi`](crate::Kalshi).
+///
+/// # Example
+/// ```
+/// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+/// use futures_util::TryStreamExt;
+/// use kalshi::{GetMarketsParams, MarketStatus};
+///
+/// let mut markets = k.stream_markets(GetMarketsParams {
+///     status: Some(MarketStatus::Open),
+///     ..Default::default()
+/// });
+/// while let Some(market) = markets.try_next().await? {
+///     println!("{}", market.ticker);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginated<'a, T> {
+    pages: BoxStream<'a, Result<Vec<T>, KalshiError>>,
+    buffer: std::vec::IntoIter<T>,
+    prefetched: VecDeque<Result<Vec<T>, KalshiError>>,
+    lookahead: usize,
+    exhausted: bool,
+}
+
+/// Caps applied by [`Paginated::collect_all`].
+///
+/// Leaving a field as `None` disables that cap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CollectLimits {
+    /// Maximum number of items to collect.
+    pub max_items: Option<usize>,
+    /// Maximum number of pages to fetch.
+    pub max_pages: Option<usize>,
+}
+
+impl CollectLimits {
+    /// Limits collection to at most `max_items` items.
+    pub fn items(max_items: usize) -> Self {
+        CollectLimits {
+            max_items: Some(max_items),
+            max_pages: None,
+        }
+    }
+
+    /// Limits collection to at most `max_pages` pages.
+    pub fn pages(max_pages: usize) -> Self {
+        CollectLimits {
+            max_items: None,
+            max_pages: Some(max_pages),
+        }
+    }
+}
+
+/// Boxes a page stream, without requiring `Send` on wasm32.
+fn boxed<'a, S: Stream + MaybeSend + 'a>(stream: S) -> BoxStream<'a, S::Item> {
+    #[cfg(not(target_arch = "wasm32"))]
+    return stream.boxed();
+    #[cfg(target_arch = "wasm32")]
+    return stream.boxed_local();
+}
+
+impl<'a, T: MaybeSend + 'a> Paginated<'a, T> {
+    /// Fetches up to `lookahead` pages ahead of the one currently being consumed.
+    ///
+    /// Pages still arrive in order, since each request needs the previous page's cursor.
+    /// The requests are not spawned: they only make progress while the stream is polled,
+    /// which happens each time an item is taken. Between polls, while the caller is busy
+    /// with an item, a started request is not advanced. This helps when items are taken
+    /// in quick bursts, e.g. forwarded to a channel read by another task, so that the
+    /// next page is usually ready when the current one runs out. For slow per-item work,
+    /// move that work to another task instead of relying on prefetching. A `lookahead`
+    /// of 0 restores lazy fetching.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # let (tx, _rx) = tokio::sync::mpsc::channel(1000);
+    /// let mut fills = k.stream_fills(None, None, Some(1000), None, None).with_prefetch(2);
+    /// // Forwarding is quick, so the stream is polled often enough to keep fetching.
+    /// while let Some(fill) = fills.try_next().await? {
+    ///     let _ = tx.send(fill).await;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_prefetch(mut self, lookahead: usize) -> Self {
+        self.lookahead = lookahead;
+        self
+    }
+
+    /// Collects every remaining item, failing once either cap in `limits` is exceeded.
+    ///
+    /// Use this instead of `try_collect` when a filter mistake could otherwise pull an
+    /// entire endpoint into memory.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<T>)`: All items, if the stream ended within the limits.
+    /// - `Err(KalshiError::PaginationLimitReached)`: If more items or pages were available than allowed.
+    /// - `Err(KalshiError)`: If fetching a page failed.
+    pub async fn collect_all(self, limits: CollectLimits) -> Result<Vec<T>, KalshiError> {
+        let mut pages = self.into_pages();
+        let mut items = Vec::new();
+        let mut page_count = 0;
+        while let Some(page) = pages.try_next().await? {
+            page_count += 1;
+            let over_pages = limits.max_pages.is_some_and(|max| page_count > max);
+            let over_items = limits
+                .max_items
+                .is_some_and(|max| items.len() + page.len() > max);
+            if over_pages || over_items {
+                return Err(KalshiError::PaginationLimitReached {
+                    items: items.len(),
+                    pages: page_count - 1,
+                });
+            }
+            items.extend(page);
+        }
+        Ok(items)
+    }
+
+    /// Collects up to `max_items` items and stops, without treating the cap as an error.
+    ///
+    /// No further pages are requested once `max_items` items have been collected.
+    pub async fn collect_at_most(mut self, max_items: usize) -> Result<Vec<T>, KalshiError> {
+        let mut items = Vec::new();
+        while items.len() < max_items {
+            match self.try_next().await? {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+        Ok(items)
+    }
+
+    /// Converts the stream into one that yields whole pages instead of single items.
+    ///
+    /// Items already buffered from a partially consumed page are yielded first as a
+    /// page of their own.
+    pub fn into_pages(mut self) -> BoxStream<'a, Result<Vec<T>, KalshiError>> {
+        let buffered: Vec<T> = std::mem::take(&mut self.buffer).collect();
+        let rest = stream::poll_fn(move |cx| self.poll_next_page(cx));
+        if buffered.is_empty() {
+            boxed(rest)
+        } else {
+            boxed(stream::once(async move { Ok(buffered) }).chain(rest))
+        }
+    }
+}
+
+impl<T> Paginated<'_, T> {
+    /// Drives the underlying page requests until `lookahead` pages are waiting.
+    fn fill_lookahead(&mut self, cx: &mut Context<'_>) {
+        while !self.exhausted && self.prefetched.len() < self.lookahead {
+            match self.pages.poll_next_unpin(cx) {
+                Poll::Ready(Some(page)) => {
+                    self.exhausted = page.is_err();
+                    self.prefetched.push_back(page);
+                }
+                Poll::Ready(None) => self.exhausted = true,
+                Poll::Pending => break,
+            }
+        }
+    }
+
+    fn poll_next_page(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Vec<T>, KalshiError>>> {
+        self.fill_lookahead(cx);
+        if let Some(page) = self.prefetched.pop_front() {
+            // Start on the page after this one before handing it out.
+            self.fill_lookahead(cx);
+            return Poll::Ready(Some(page));
+        }
+        if self.exhausted {
+            return Poll::Ready(None);
+        }
+        let page = ready!(self.pages.poll_next_unpin(cx));
+        self.exhausted = !matches!(page, Some(Ok(_)));
+        Poll::Ready(page)
+    }
+}
+
+impl<T> Unpin for Paginated<'_, T> {}
+
+impl<T> Stream for Paginated<'_, T> {
+    type Item = Result<T, KalshiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.buffer.next() {
+                self.fill_lookahead(cx);
+                return Poll::Ready(Some(Ok(item)));
+            }
+            match ready!(self.poll_next_page(cx)) {
+                Some(Ok(page)) => self.buffer = page.into_iter(),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Turns a cursor-based endpoint into a [`Paginated`] stream.
+///
+/// `fetch` is called with the cursor for each page (starting with `start_cursor`) and
+/// returns that page's items along with the cursor for the next one. Pagination stops
+/// when the returned cursor is `None` or empty.
+///
+/// # Example
+/// ```
+/// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+/// use futures_util::TryStreamExt;
+///
+/// let settlements: Vec<_> = kalshi::paginate(None, |cursor| {
+///     k.get_portfolio_settlements(Some(200), cursor)
+/// })
+/// .try_collect()
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<'a, T, F, Fut>(start_cursor: Option<String>, mut fetch: F) -> Paginated<'a, T>
+where
+    T: MaybeSend + 'a,
+    F: FnMut(Option<String>) -> Fut + MaybeSend + 'a,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), KalshiError>> + MaybeSend + 'a,
+{
+    // `None` once the last page has been fetched, otherwise the cursor of the next page.
+    let pages = stream::try_unfold(Some(start_cursor), move |next| {
+        let request = next.map(&mut fetch);
+        async move {
+            let Some(request) = request else {
+                return Ok(None);
+            };
+            let (items, cursor) = request.await?;
+            let next = cursor.filter(|c| !c.is_empty()).map(Some);
+            Ok(Some((items, next)))
+        }
+    });
+    Paginated {
+        pages: boxed(pages),
+        buffer: Vec::new().into_iter(),
+        prefetched: VecDeque::new(),
+        lookahead: 0,
+        exhausted: false,
+    }
+}
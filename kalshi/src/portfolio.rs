@@ -1,8 +1,13 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use crate::{paginate, Paginated};
+use futures_util::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Maximum number of orders Kalshi cancels in one [`Kalshi::batch_cancel_order`] request.
+pub const MAX_ORDERS_PER_BATCH_CANCEL: usize = 20;
+
 impl Kalshi {
     /// Retrieves the balance and portfolio value for the authenticated user.
     pub async fn get_balance(&self) -> Result<BalanceResponse, KalshiError> {
@@ -11,6 +16,7 @@ impl Kalshi {
     }
 
     /// Retrieves multiple orders for the authenticated user with optional filters.
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_multiple_orders(
         &self,
         ticker: Option<String>,
@@ -35,6 +41,32 @@ impl Kalshi {
         Ok((resp.orders, resp.cursor))
     }
 
+    /// Streams every order matching the filters, fetching further pages as needed.
+    ///
+    /// Takes the same filters as [`get_multiple_orders`](Kalshi::get_multiple_orders),
+    /// with `limit` setting the page size.
+    pub fn stream_orders(
+        &self,
+        ticker: Option<String>,
+        event_ticker: Option<String>,
+        status: Option<String>,
+        limit: Option<i64>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+    ) -> Paginated<'_, Order> {
+        paginate(None, move |cursor| {
+            self.get_multiple_orders(
+                ticker.clone(),
+                event_ticker.clone(),
+                status.clone(),
+                limit,
+                cursor,
+                min_ts,
+                max_ts,
+            )
+        })
+    }
+
     /// Retrieves a single order by its ID.
     pub async fn get_single_order(&self, order_id: &str) -> Result<Order, KalshiError> {
         let path = format!("/portfolio/orders/{}", order_id);
@@ -59,7 +91,10 @@ impl Kalshi {
     ) -> Result<DecreaseOrderResponse, KalshiError> {
         let path = format!("/portfolio/orders/{}/decrease", order_id);
         let url = self.build_url(&path)?;
-        let payload = DecreaseOrderPayload { reduce_by, reduce_to };
+        let payload = DecreaseOrderPayload {
+            reduce_by,
+            reduce_to,
+        };
         self.http_post(url, &payload).await
     }
 
@@ -86,6 +121,30 @@ impl Kalshi {
         Ok((resp.fills, resp.cursor))
     }
 
+    /// Streams every fill matching the filters, fetching further pages as needed.
+    ///
+    /// Takes the same filters as [`get_multiple_fills`](Kalshi::get_multiple_fills),
+    /// with `limit` setting the page size.
+    pub fn stream_fills(
+        &self,
+        ticker: Option<String>,
+        order_id: Option<String>,
+        limit: Option<i64>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+    ) -> Paginated<'_, Fill> {
+        paginate(None, move |cursor| {
+            self.get_multiple_fills(
+                ticker.clone(),
+                order_id.clone(),
+                limit,
+                cursor,
+                min_ts,
+                max_ts,
+            )
+        })
+    }
+
     /// Retrieves portfolio settlements.
     pub async fn get_portfolio_settlements(
         &self,
@@ -101,6 +160,16 @@ impl Kalshi {
         Ok((resp.settlements, resp.cursor))
     }
 
+    /// Streams every portfolio settlement, fetching further pages as needed.
+    ///
+    /// # Arguments
+    /// * `limit` - Optional page size.
+    pub fn stream_portfolio_settlements(&self, limit: Option<i64>) -> Paginated<'_, Settlement> {
+        paginate(None, move |cursor| {
+            self.get_portfolio_settlements(limit, cursor)
+        })
+    }
+
     /// Retrieves user positions across markets and events.
     pub async fn get_user_positions(
         &self,
@@ -127,7 +196,10 @@ impl Kalshi {
     }
 
     /// Batch cancels multiple orders.
-    pub async fn batch_cancel_order(&self, order_ids: Vec<String>) -> Result<Vec<DeleteOrderResponse>, KalshiError> {
+    pub async fn batch_cancel_order(
+        &self,
+        order_ids: Vec<String>,
+    ) -> Result<Vec<DeleteOrderResponse>, KalshiError> {
         let url = self.build_url("/portfolio/orders/batched")?;
         #[derive(Serialize)]
         struct BatchCancelRequest {
@@ -138,13 +210,52 @@ impl Kalshi {
             order_id: String,
         }
         let payload = BatchCancelRequest {
-            orders: order_ids.into_iter().map(|id| BatchCancelItem { order_id: id }).collect(),
+            orders: order_ids
+                .into_iter()
+                .map(|id| BatchCancelItem { order_id: id })
+                .collect(),
         };
         let resp: BatchCancelOrdersResponse = self.http_delete_with_body(url, &payload).await?;
-        Ok(resp.orders.into_iter().map(|o| DeleteOrderResponse {
-            order: o.order,
-            reduced_by: o.reduced_by,
-        }).collect())
+        Ok(resp
+            .orders
+            .into_iter()
+            .map(|o| DeleteOrderResponse {
+                order: o.order,
+                reduced_by: o.reduced_by,
+            })
+            .collect())
+    }
+
+    /// Cancels every resting order of the authenticated user.
+    ///
+    /// Kalshi has no single cancel-all endpoint, so resting orders are listed and then
+    /// cancelled in batches of [`MAX_ORDERS_PER_BATCH_CANCEL`]. A batch that fails does
+    /// not stop the remaining ones; check [`CancelAllOrdersReport::is_complete`] and
+    /// call again to retry. Orders placed while this runs may survive.
+    ///
+    /// # Returns
+    /// - `Ok(CancelAllOrdersReport)`: The cancelled orders and every batch that failed.
+    /// - `Err(KalshiError)`: If the resting orders could not be listed.
+    pub async fn cancel_all_orders(&self) -> Result<CancelAllOrdersReport, KalshiError> {
+        let order_ids: Vec<String> = self
+            .stream_orders(None, None, Some("resting".to_string()), None, None, None)
+            .map_ok(|order| order.order_id)
+            .try_collect()
+            .await?;
+        let mut report = CancelAllOrdersReport {
+            cancelled: Vec::with_capacity(order_ids.len()),
+            failed: Vec::new(),
+        };
+        for batch in order_ids.chunks(MAX_ORDERS_PER_BATCH_CANCEL) {
+            match self.batch_cancel_order(batch.to_vec()).await {
+                Ok(cancelled) => report.cancelled.extend(cancelled),
+                Err(error) => report.failed.push(FailedCancelBatch {
+                    order_ids: batch.to_vec(),
+                    error,
+                }),
+            }
+        }
+        Ok(report)
     }
 
     // Order Group Management
@@ -157,14 +268,20 @@ impl Kalshi {
     }
 
     /// Creates a new order group.
-    pub async fn create_order_group(&self, payload: CreateOrderGroupRequest) -> Result<String, KalshiError> {
+    pub async fn create_order_group(
+        &self,
+        payload: CreateOrderGroupRequest,
+    ) -> Result<String, KalshiError> {
         let url = self.build_url("/portfolio/order_groups/create")?;
         let resp: CreateOrderGroupResponse = self.http_post(url, &payload).await?;
         Ok(resp.order_group_id)
     }
 
     /// Retrieves a single order group by its ID.
-    pub async fn get_order_group(&self, order_group_id: &str) -> Result<GetOrderGroupResponse, KalshiError> {
+    pub async fn get_order_group(
+        &self,
+        order_group_id: &str,
+    ) -> Result<GetOrderGroupResponse, KalshiError> {
         let path = format!("/portfolio/order_groups/{}", order_group_id);
         let url = self.build_url(&path)?;
         self.http_get(url).await
@@ -192,7 +309,11 @@ impl Kalshi {
     }
 
     /// Updates the limit for an order group.
-    pub async fn update_order_group_limit(&self, order_group_id: &str, payload: UpdateOrderGroupLimitRequest) -> Result<(), KalshiError> {
+    pub async fn update_order_group_limit(
+        &self,
+        order_group_id: &str,
+        payload: UpdateOrderGroupLimitRequest,
+    ) -> Result<(), KalshiError> {
         let path = format!("/portfolio/order_groups/{}/limit", order_group_id);
         let url = self.build_url(&path)?;
         self.http_put(url, &payload).await
@@ -208,7 +329,10 @@ impl Kalshi {
     }
 
     /// Transfers funds between subaccounts.
-    pub async fn transfer_between_subaccounts(&self, payload: ApplySubaccountTransferRequest) -> Result<(), KalshiError> {
+    pub async fn transfer_between_subaccounts(
+        &self,
+        payload: ApplySubaccountTransferRequest,
+    ) -> Result<(), KalshiError> {
         let url = self.build_url("/portfolio/subaccounts/transfer")?;
         self.http_post(url, &payload).await
     }
@@ -221,7 +345,11 @@ impl Kalshi {
     }
 
     /// Retrieves transfers between subaccounts.
-    pub async fn get_subaccount_transfers(&self, limit: Option<i64>, cursor: Option<String>) -> Result<(Vec<SubaccountTransfer>, Option<String>), KalshiError> {
+    pub async fn get_subaccount_transfers(
+        &self,
+        limit: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<SubaccountTransfer>, Option<String>), KalshiError> {
         let mut params = Vec::new();
         add_param!(params, "limit", limit);
         add_param!(params, "cursor", cursor);
@@ -231,13 +359,18 @@ impl Kalshi {
     }
 
     /// Updates netting settings for a subaccount.
-    pub async fn update_subaccount_netting(&self, payload: UpdateSubaccountNettingRequest) -> Result<(), KalshiError> {
+    pub async fn update_subaccount_netting(
+        &self,
+        payload: UpdateSubaccountNettingRequest,
+    ) -> Result<(), KalshiError> {
         let url = self.build_url("/portfolio/subaccounts/netting")?;
         self.http_put(url, &payload).await
     }
 
     /// Retrieves netting settings for all subaccounts.
-    pub async fn get_subaccount_netting(&self) -> Result<Vec<SubaccountNettingConfig>, KalshiError> {
+    pub async fn get_subaccount_netting(
+        &self,
+    ) -> Result<Vec<SubaccountNettingConfig>, KalshiError> {
         let url = self.build_url("/portfolio/subaccounts/netting")?;
         let resp: GetSubaccountNettingResponse = self.http_get(url).await?;
         Ok(resp.netting_configs)
@@ -277,6 +410,29 @@ pub struct DeleteOrderResponse {
     pub reduced_by: i32,
 }
 
+/// Outcome of [`Kalshi::cancel_all_orders`].
+#[derive(Debug)]
+pub struct CancelAllOrdersReport {
+    /// Cancellation of each order in the batches that succeeded.
+    pub cancelled: Vec<DeleteOrderResponse>,
+    /// Batches that could not be cancelled. Their orders may still be live.
+    pub failed: Vec<FailedCancelBatch>,
+}
+
+impl CancelAllOrdersReport {
+    /// Returns `true` if every batch was cancelled.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A batch of [`Kalshi::cancel_all_orders`] that failed.
+#[derive(Debug)]
+pub struct FailedCancelBatch {
+    pub order_ids: Vec<String>,
+    pub error: KalshiError,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DecreaseOrderResponse {
     pub order: Order,
@@ -612,4 +768,4 @@ struct GetPortfolioRestingOrderTotalValueResponse {
 pub struct SubaccountNettingConfig {
     pub subaccount_number: u32,
     pub enabled: bool,
-}
\ No newline at end of file
+}
@@ -0,0 +1,116 @@
+#[cfg(any(feature = "websockets", not(target_arch = "wasm32")))]
+use crate::kalshi_error::*;
+#[cfg(feature = "websockets")]
+use url::Url;
+
+/// A proxy for REST and websocket connections, set with
+/// [`KalshiBuilder::proxy`](crate::KalshiBuilder::proxy).
+///
+/// `http://` proxies are used with `CONNECT` tunnels. `socks5://` and `socks5h://`
+/// proxies need the `socks` feature. Credentials can be given in the URL or with
+/// [`basic_auth`](ProxyConfig::basic_auth).
+///
+/// # Example
+/// ```
+/// # use kalshi::ProxyConfig;
+/// let proxy = ProxyConfig::new("http://proxy.corp.example:3128")
+///     .basic_auth("trader", "secret")
+///     .no_proxy(["localhost", ".corp.example"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// URL of the proxy, e.g. `http://proxy:3128` or `socks5://proxy:1080`.
+    pub url: String,
+    /// User name for proxy authentication.
+    pub username: Option<String>,
+    /// Password for proxy authentication.
+    pub password: Option<String>,
+    /// Hosts reached directly instead of through the proxy. An entry matches the host
+    /// itself and its subdomains; `*` matches every host.
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// Creates a proxy configuration without authentication or exceptions.
+    pub fn new(url: impl Into<String>) -> Self {
+        ProxyConfig {
+            url: url.into(),
+            username: None,
+            password: None,
+            no_proxy: Vec::new(),
+        }
+    }
+
+    /// Authenticates with the proxy using a user name and password.
+    pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Adds hosts that are reached directly instead of through the proxy.
+    pub fn no_proxy<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.no_proxy.extend(hosts.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns whether connections to `host` skip the proxy.
+    pub fn bypasses(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.no_proxy.iter().any(|entry| {
+            let entry = entry.trim().trim_start_matches('.').to_ascii_lowercase();
+            entry == "*"
+                || host == entry
+                || host
+                    .strip_suffix(&entry)
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+    }
+
+    /// Parses the proxy URL.
+    #[cfg(feature = "websockets")]
+    pub(crate) fn parsed_url(&self) -> Result<Url, KalshiError> {
+        Url::parse(&self.url).map_err(|e| {
+            KalshiError::UserInputError(format!("Invalid proxy URL {}: {}", self.url, e))
+        })
+    }
+
+    /// Returns the credentials to authenticate with, preferring explicit ones over those
+    /// in the URL.
+    #[cfg(feature = "websockets")]
+    pub(crate) fn credentials(&self) -> Result<Option<(String, String)>, KalshiError> {
+        if let Some(username) = &self.username {
+            return Ok(Some((
+                username.clone(),
+                self.password.clone().unwrap_or_default(),
+            )));
+        }
+        let url = self.parsed_url()?;
+        if url.username().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some((
+            url.username().to_string(),
+            url.password().unwrap_or_default().to_string(),
+        )))
+    }
+
+    /// Builds the equivalent proxy for the REST client.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn to_reqwest(&self) -> Result<reqwest::Proxy, KalshiError> {
+        let mut proxy = reqwest::Proxy::all(self.url.as_str()).map_err(|e| {
+            KalshiError::UserInputError(format!("Invalid proxy URL {}: {}", self.url, e))
+        })?;
+        if let Some(username) = &self.username {
+            proxy = proxy.basic_auth(username, self.password.as_deref().unwrap_or_default());
+        }
+        if !self.no_proxy.is_empty() {
+            proxy = proxy.no_proxy(reqwest::NoProxy::from_string(&self.no_proxy.join(",")));
+        }
+        Ok(proxy)
+    }
+}
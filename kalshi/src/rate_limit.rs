@@ -0,0 +1,376 @@
+use crate::Kalshi;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
+
+/// Called with the rate limit state of every response that reports one, see
+/// [`Kalshi::on_rate_limit`].
+pub type RateLimitHook = Arc<dyn Fn(&RateLimitInfo) + Send + Sync>;
+
+/// Rate limit state reported by the `X-RateLimit-*` and `Retry-After` headers of a
+/// response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window, from `X-RateLimit-Limit`.
+    pub limit: Option<u64>,
+    /// Requests left in the current window, from `X-RateLimit-Remaining`.
+    pub remaining: Option<u64>,
+    /// Time until the window resets, from `X-RateLimit-Reset`.
+    pub reset: Option<Duration>,
+    /// How long to wait before sending again, from `Retry-After`.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Reads the rate limit headers of a response, or returns `None` if it has none.
+    ///
+    /// `X-RateLimit-Reset` is accepted both as seconds until the reset and as a Unix
+    /// timestamp; `Retry-After` both as seconds and as an HTTP date.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+        let number = |name: &str| header(name).and_then(|value| value.parse::<u64>().ok());
+        let info = RateLimitInfo {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            reset: number("x-ratelimit-reset").map(until_reset),
+            retry_after: header(RETRY_AFTER.as_str()).and_then(parse_retry_after),
+        };
+        (info != RateLimitInfo::default()).then_some(info)
+    }
+
+    /// Returns `true` if the window has no requests left.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+}
+
+/// Values this large are Unix timestamps rather than a number of seconds.
+const UNIX_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+fn until_reset(reset: u64) -> Duration {
+    if reset < UNIX_TIMESTAMP_THRESHOLD {
+        return Duration::from_secs(reset);
+    }
+    let now = Utc::now().timestamp().max(0) as u64;
+    Duration::from_secs(reset.saturating_sub(now))
+}
+
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+impl Kalshi {
+    /// Calls `hook` with the rate limit state of every REST response that reports one,
+    /// so callers can slow down before Kalshi starts rejecting requests. Replaces any
+    /// hook set before.
+    ///
+    /// The same state is attached to failed calls as the `rate_limit` of
+    /// [`KalshiError::ApiError`](crate::KalshiError::ApiError).
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::Kalshi;
+    /// # fn example(kalshi: Kalshi) -> Kalshi {
+    /// kalshi.on_rate_limit(|info| {
+    ///     if info.is_exhausted() {
+    ///         tracing::warn!("rate limit exhausted, resets in {:?}", info.reset);
+    ///     }
+    /// })
+    /// # }
+    /// ```
+    pub fn on_rate_limit<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RateLimitInfo) + Send + Sync + 'static,
+    {
+        self.rate_limit_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Reads the rate limit headers of a response and passes them to the hook.
+    pub(crate) fn observe_rate_limit(&self, headers: &HeaderMap) -> Option<RateLimitInfo> {
+        let info = RateLimitInfo::from_headers(headers)?;
+        if let Some(hook) = &self.rate_limit_hook {
+            hook(&info);
+        }
+        Some(info)
+    }
+}
+
+/// Kalshi API access tiers, with their documented request limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitTier {
+    /// 20 reads and 10 writes per second.
+    Basic,
+    /// 30 reads and 30 writes per second.
+    Advanced,
+    /// 100 reads and 100 writes per second.
+    Premier,
+}
+
+/// Request rates enforced on the client side, set with [`Kalshi::with_rate_limits`], or
+/// with [`Kalshi::with_reloadable_rate_limits`] to retune them while running.
+///
+/// Reads and writes draw from separate token buckets. Writes are every request that is
+/// not a GET, such as placing, amending and cancelling orders. Each bucket holds one
+/// second worth of requests, so short bursts up to the rate go out immediately and
+/// requests beyond it wait for a token instead of earning a rejection or a temporary
+/// ban.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct RateLimits {
+    /// Reads allowed per second.
+    pub reads_per_second: f64,
+    /// Writes allowed per second.
+    pub writes_per_second: f64,
+}
+
+impl RateLimits {
+    /// Creates limits with the given rates, each at least one request per second.
+    pub fn new(reads_per_second: f64, writes_per_second: f64) -> Self {
+        RateLimits {
+            reads_per_second: reads_per_second.max(1.0),
+            writes_per_second: writes_per_second.max(1.0),
+        }
+    }
+
+    /// Returns the limits of an access tier.
+    pub fn tier(tier: RateLimitTier) -> Self {
+        match tier {
+            RateLimitTier::Basic => RateLimits::new(20.0, 10.0),
+            RateLimitTier::Advanced => RateLimits::new(30.0, 30.0),
+            RateLimitTier::Premier => RateLimits::new(100.0, 100.0),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl crate::Validate for RateLimits {
+    fn validate(&self) -> Result<(), crate::KalshiError> {
+        for (name, rate) in [
+            ("reads_per_second", self.reads_per_second),
+            ("writes_per_second", self.writes_per_second),
+        ] {
+            if !rate.is_finite() || rate < 1.0 {
+                return Err(crate::KalshiError::UserInputError(format!(
+                    "{} must be at least 1, got {}",
+                    name, rate
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<RateLimitTier> for RateLimits {
+    fn from(tier: RateLimitTier) -> Self {
+        RateLimits::tier(tier)
+    }
+}
+
+/// A token bucket refilled continuously at a fixed rate.
+#[cfg(not(target_arch = "wasm32"))]
+struct TokenBucket {
+    /// Rate, available tokens and when they were last counted.
+    state: std::sync::Mutex<(f64, f64, tokio::time::Instant)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokenBucket {
+    fn new(rate: f64) -> Self {
+        TokenBucket {
+            state: std::sync::Mutex::new((rate, rate, tokio::time::Instant::now())),
+        }
+    }
+
+    /// Changes the rate, keeping the tokens already earned up to one second's worth.
+    fn set_rate(&self, rate: f64) {
+        let mut state = self.state.lock().unwrap();
+        let now = tokio::time::Instant::now();
+        let (old_rate, tokens, last) = *state;
+        let tokens = (tokens + now.duration_since(last).as_secs_f64() * old_rate).min(rate);
+        *state = (rate, tokens, now);
+    }
+
+    /// Waits until a token is available and takes it.
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = tokio::time::Instant::now();
+                let (rate, tokens, last) = *state;
+                let tokens = (tokens + now.duration_since(last).as_secs_f64() * rate).min(rate);
+                if tokens >= 1.0 {
+                    *state = (rate, tokens - 1.0, now);
+                    return;
+                }
+                *state = (rate, tokens, now);
+                Duration::from_secs_f64((1.0 - tokens) / rate)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Read and write buckets shared between clones of a client.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct ClientRateLimiter {
+    reads: TokenBucket,
+    writes: TokenBucket,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ClientRateLimiter {
+    fn new(limits: RateLimits) -> Self {
+        ClientRateLimiter {
+            reads: TokenBucket::new(limits.reads_per_second),
+            writes: TokenBucket::new(limits.writes_per_second),
+        }
+    }
+
+    fn set_limits(&self, limits: RateLimits) {
+        self.reads.set_rate(limits.reads_per_second);
+        self.writes.set_rate(limits.writes_per_second);
+    }
+
+    /// Waits until a request with the given method may be sent.
+    pub(crate) async fn acquire(&self, method: &reqwest::Method) {
+        if *method == reqwest::Method::GET {
+            self.reads.acquire().await
+        } else {
+            self.writes.acquire().await
+        }
+    }
+}
+
+impl Kalshi {
+    /// Paces REST requests to stay within `limits`, e.g.
+    /// `kalshi.with_rate_limits(Some(RateLimitTier::Basic.into()))`. Clones of this
+    /// client share the same buckets. `None` disables client-side limiting, which is
+    /// the default. Not available on wasm32.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    pub fn with_rate_limits(mut self, limits: Option<RateLimits>) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.rate_limiter = limits.map(|limits| Arc::new(ClientRateLimiter::new(limits)));
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = limits;
+        self
+    }
+
+    /// Paces REST requests like [`with_rate_limits`](Kalshi::with_rate_limits), picking
+    /// up every new value applied to `limits`, e.g. from
+    /// [`Reloadable::watch_file`]. Requests waiting for a token see the new rates on
+    /// their next check. Must be called within a Tokio runtime.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::{RateLimitTier, RateLimits, Reloadable};
+    /// use std::time::Duration;
+    ///
+    /// let limits = Reloadable::new(RateLimits::tier(RateLimitTier::Basic))?;
+    /// let k = k.with_reloadable_rate_limits(&limits);
+    /// // Later, e.g. after an upgrade to the advanced tier:
+    /// limits.reload(RateLimitTier::Advanced.into())?;
+    /// # let _watcher = limits.watch_file("rate_limits.json", Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_reloadable_rate_limits(mut self, limits: &crate::Reloadable<RateLimits>) -> Self {
+        let limiter = Arc::new(ClientRateLimiter::new(*limits.current()));
+        let weak = Arc::downgrade(&limiter);
+        let mut changes = limits.subscribe();
+        tokio::spawn(async move {
+            while changes.changed().await.is_ok() {
+                // Stop once every clone of the client is gone.
+                let Some(limiter) = weak.upgrade() else {
+                    return;
+                };
+                let new = **changes.borrow_and_update();
+                limiter.set_limits(new);
+                tracing::info!("Applied reloaded rate limits {:?}", new);
+            }
+        });
+        self.rate_limiter = Some(limiter);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use reqwest::Method;
+    use tokio::time::{advance, Instant};
+
+    /// Takes every token available right now and returns how many there were.
+    fn drain(bucket: &TokenBucket) -> usize {
+        let mut taken = 0;
+        while bucket.acquire().now_or_never().is_some() {
+            taken += 1;
+        }
+        taken
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bucket_refills_at_its_rate() {
+        let bucket = TokenBucket::new(10.0);
+        assert_eq!(drain(&bucket), 10);
+
+        let start = Instant::now();
+        bucket.acquire().await;
+        let waited = start.elapsed();
+        assert!(
+            waited >= Duration::from_millis(99) && waited <= Duration::from_millis(101),
+            "waited {:?}",
+            waited
+        );
+
+        advance(Duration::from_millis(500)).await;
+        assert_eq!(drain(&bucket), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bucket_bursts_at_most_one_second_of_tokens() {
+        let bucket = TokenBucket::new(5.0);
+        advance(Duration::from_secs(60)).await;
+        assert_eq!(drain(&bucket), 5);
+
+        advance(Duration::from_secs(60)).await;
+        assert_eq!(drain(&bucket), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limiter_switches_tiers() {
+        let limiter = ClientRateLimiter::new(RateLimitTier::Basic.into());
+        assert_eq!(drain(&limiter.writes), 10);
+
+        // Upgrading keeps the empty bucket but refills it at the new rate and cap.
+        limiter.set_limits(RateLimitTier::Advanced.into());
+        assert!(limiter.acquire(&Method::POST).now_or_never().is_none());
+        advance(Duration::from_secs(2)).await;
+        assert_eq!(drain(&limiter.writes), 30);
+
+        // Downgrading caps the tokens already earned at the new burst size.
+        limiter.set_limits(RateLimitTier::Premier.into());
+        advance(Duration::from_secs(1)).await;
+        limiter.set_limits(RateLimitTier::Basic.into());
+        assert_eq!(drain(&limiter.reads), 20);
+        assert_eq!(drain(&limiter.writes), 10);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::Kalshi;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// JSON fields masked in logged bodies unless
+/// [`Kalshi::with_sensitive_fields`] replaces them.
+pub const DEFAULT_SENSITIVE_FIELDS: &[&str] = &["private_key", "password", "token"];
+
+/// Headers whose values are never logged.
+#[cfg(feature = "websockets")]
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "kalshi-access-signature",
+];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// How request and response bodies appear in logs, set with
+/// [`Kalshi::with_body_logging`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BodyLogging {
+    /// Bodies are logged verbatim.
+    Full,
+    /// Sensitive JSON fields are masked before logging.
+    #[default]
+    Redacted,
+    /// Bodies are left out of logs entirely, e.g. in production.
+    Off,
+}
+
+/// Masks secrets before bodies reach logs or error messages.
+#[derive(Debug, Clone)]
+pub(crate) struct Redaction {
+    logging: BodyLogging,
+    fields: Arc<Vec<String>>,
+}
+
+impl Default for Redaction {
+    fn default() -> Self {
+        Redaction {
+            logging: BodyLogging::default(),
+            fields: Arc::new(
+                DEFAULT_SENSITIVE_FIELDS
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Redaction {
+    /// Renders a body for a log line according to the body logging setting.
+    pub(crate) fn log_body(&self, bytes: &[u8]) -> String {
+        match self.logging {
+            BodyLogging::Full => String::from_utf8_lossy(bytes).into_owned(),
+            BodyLogging::Redacted => self.redact(bytes),
+            BodyLogging::Off => "<omitted>".to_string(),
+        }
+    }
+
+    /// Renders a body with its sensitive JSON fields masked. Bodies that are not JSON
+    /// are returned as they are.
+    pub(crate) fn redact(&self, bytes: &[u8]) -> String {
+        match serde_json::from_slice::<Value>(bytes) {
+            Ok(mut value) => {
+                self.mask(&mut value);
+                value.to_string()
+            }
+            Err(_) => String::from_utf8_lossy(bytes).into_owned(),
+        }
+    }
+
+    fn mask(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if self.fields.iter().any(|f| f.eq_ignore_ascii_case(key)) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.mask(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.mask(item)),
+            _ => {}
+        }
+    }
+}
+
+/// Renders headers for a log line, masking credentials and signatures.
+#[cfg(feature = "websockets")]
+pub(crate) fn redact_headers<'a>(headers: impl Iterator<Item = (&'a str, &'a [u8])>) -> String {
+    headers
+        .map(|(name, value)| {
+            if SENSITIVE_HEADERS
+                .iter()
+                .any(|h| h.eq_ignore_ascii_case(name))
+            {
+                format!("{}: {}", name, REDACTED)
+            } else {
+                format!("{}: {}", name, String::from_utf8_lossy(value))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Kalshi {
+    /// Sets how request and response bodies appear in logs. Defaults to
+    /// [`BodyLogging::Redacted`]. Error messages always carry the redacted body.
+    pub fn with_body_logging(mut self, logging: BodyLogging) -> Self {
+        self.redaction.logging = logging;
+        self
+    }
+
+    /// Replaces the JSON field names masked in logged bodies and error messages.
+    /// Matching ignores case and applies at any depth. Defaults to
+    /// [`DEFAULT_SENSITIVE_FIELDS`].
+    pub fn with_sensitive_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redaction.fields = Arc::new(fields.into_iter().map(Into::into).collect());
+        self
+    }
+}
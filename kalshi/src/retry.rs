@@ -0,0 +1,229 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{kalshi_error::RETRYABLE_STATUSES, RateLimitInfo};
+use crate::{transport::HttpResponse, Kalshi, KalshiError};
+use reqwest::{Method, Url};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
+
+/// Retries idempotent REST requests that failed transiently, set with
+/// [`Kalshi::with_retry_policy`] or [`KalshiBuilder::retry_policy`](crate::KalshiBuilder::retry_policy).
+///
+/// GET, HEAD and PUT requests answered with 429, 502, 503 or 504, or that hit a
+/// timeout or a connection error, are sent again after an exponential backoff, or
+/// after the `Retry-After` of the response if that is longer.
+/// Order placement and every other POST is never retried, since the exchange may
+/// already have acted on it. Neither are DELETE requests such as order cancels: a
+/// cancel that went through before timing out would be answered with a 404 when sent
+/// again, hiding that it succeeded.
+///
+/// # Example
+/// ```
+/// # use kalshi::RetryPolicy;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new(5)
+///     .backoff(Duration::from_millis(200), Duration::from_secs(5))
+///     .max_elapsed(Duration::from_secs(20))
+///     .jitter(0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt. Defaults to 3.
+    pub max_retries: u32,
+    /// Delay before the first retry. Defaults to 100 milliseconds.
+    pub initial_backoff: Duration,
+    /// Longest delay between retries. Defaults to 5 seconds.
+    pub max_backoff: Duration,
+    /// Time after the first attempt past which no retry is started. Defaults to 30
+    /// seconds; `None` only limits the number of retries.
+    pub max_elapsed: Option<Duration>,
+    /// Largest share of a delay, between 0 and 1, removed at random. Defaults to 0.5.
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    /// Creates the default policy with `max_retries` retries.
+    pub fn new(max_retries: u32) -> Self {
+        RetryPolicy {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the delay before the first retry and the longest delay between retries.
+    pub fn backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff.max(initial_backoff);
+        self
+    }
+
+    /// Stops retrying once `max_elapsed` has passed since the first attempt.
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Sets the largest share of each delay removed at random, clamped to `0..=1`.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the delay before retry `retry`, counting from 1.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn delay(&self, retry: u32) -> Duration {
+        backoff_delay(self.initial_backoff, self.max_backoff, self.jitter, retry)
+    }
+}
+
+/// Returns the delay before attempt `attempt`, counting from 1: `initial * 2^(attempt-1)`
+/// capped at `max`, then shortened by a random share of up to `jitter`.
+///
+/// Shared by [`RetryPolicy`] and the websocket reconnect policy.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn backoff_delay(
+    initial: Duration,
+    max: Duration,
+    jitter: f64,
+    attempt: u32,
+) -> Duration {
+    let doublings = attempt.saturating_sub(1).min(31);
+    let delay = initial.saturating_mul(1 << doublings).min(max);
+    if jitter <= 0.0 {
+        return delay;
+    }
+    // The random bits of a v4 UUID are plenty for spreading out retries.
+    let random = (uuid::Uuid::new_v4().as_u128() as u64) as f64 / u64::MAX as f64;
+    delay.mul_f64(1.0 - jitter.min(1.0) * random)
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            max_elapsed: Some(Duration::from_secs(30)),
+            jitter: 0.5,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_safe_to_retry(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::PUT)
+}
+
+impl Kalshi {
+    /// Retries idempotent REST requests that failed transiently, see [`RetryPolicy`].
+    /// `None` disables retries, which is the default. Not available on wasm32.
+    pub fn with_retry_policy(mut self, policy: Option<RetryPolicy>) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sends a request, retrying it according to the retry policy.
+    ///
+    /// Once retries are exhausted, the last response is returned as is, so a final 429
+    /// or 5xx still surfaces as [`KalshiError::ApiError`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, KalshiError> {
+        let policy = match &self.retry_policy {
+            Some(policy) if is_safe_to_retry(&method) => policy,
+            _ => return self.send_once(method, url, body).await,
+        };
+        let started = tokio::time::Instant::now();
+        let mut retry = 0;
+        loop {
+            let res = self.send_once(method.clone(), url, body.clone()).await;
+            let retryable = match &res {
+                Ok(resp) => RETRYABLE_STATUSES.contains(&resp.status.as_u16()),
+                Err(e) => e.is_retryable(),
+            };
+            retry += 1;
+            let mut delay = policy.delay(retry);
+            // Never retry sooner than Kalshi asked to.
+            if let Some(retry_after) = res
+                .as_ref()
+                .ok()
+                .and_then(|resp| RateLimitInfo::from_headers(&resp.headers))
+                .and_then(|info| info.retry_after)
+            {
+                delay = delay.max(retry_after);
+            }
+            let out_of_time = policy
+                .max_elapsed
+                .is_some_and(|max| started.elapsed() + delay > max);
+            if !retryable || retry > policy.max_retries || out_of_time {
+                return res;
+            }
+            match &res {
+                Ok(resp) => warn!(
+                    "{} {} answered {}, retry {} in {:?}",
+                    method, url, resp.status, retry, delay
+                ),
+                Err(e) => warn!(
+                    "{} {} failed: {}, retry {} in {:?}",
+                    method, url, e, retry, delay
+                ),
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    // Retries need timers, which wasm32 does not have.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, KalshiError> {
+        self.send_once(method, url, body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::new(10)
+            .backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .jitter(0.0);
+        let delays: Vec<u64> = (1..=6)
+            .map(|retry| policy.delay(retry).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        // Huge retry counts must not overflow the shift.
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn jitter_only_shortens_delays_within_its_share() {
+        let policy = RetryPolicy::new(10)
+            .backoff(Duration::from_millis(400), Duration::from_secs(1))
+            .jitter(0.25);
+        for _ in 0..1000 {
+            let delay = policy.delay(1);
+            assert!(delay <= Duration::from_millis(400), "{:?}", delay);
+            assert!(delay >= Duration::from_millis(300), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn only_idempotent_methods_are_retried() {
+        for method in [Method::GET, Method::HEAD, Method::PUT] {
+            assert!(is_safe_to_retry(&method), "{}", method);
+        }
+        for method in [Method::POST, Method::DELETE, Method::PATCH] {
+            assert!(!is_safe_to_retry(&method), "{}", method);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::Kalshi;
+use reqwest::{Method, Url};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot;
+
+/// Priority class of a REST request, highest first.
+///
+/// Requests are classified from their method and path: order placement and
+/// amendments are [`Order`](RequestPriority::Order), cancellations
+/// [`Cancel`](RequestPriority::Cancel), historical endpoints
+/// [`Backfill`](RequestPriority::Backfill) and every other read
+/// [`MarketData`](RequestPriority::MarketData). A client can override the class with
+/// [`Kalshi::with_request_priority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestPriority {
+    Order,
+    Cancel,
+    MarketData,
+    Backfill,
+}
+
+impl RequestPriority {
+    /// Classifies a request from its method and URL.
+    pub fn classify(method: &Method, url: &Url) -> Self {
+        let path = url.path();
+        let trading =
+            path.contains("/portfolio/orders") || path.contains("/portfolio/order_groups");
+        if trading && (method == Method::DELETE || path.ends_with("/cancel")) {
+            RequestPriority::Cancel
+        } else if *method != Method::GET {
+            RequestPriority::Order
+        } else if path.contains("/historical") {
+            RequestPriority::Backfill
+        } else {
+            RequestPriority::MarketData
+        }
+    }
+
+    fn is_trading(self) -> bool {
+        matches!(self, RequestPriority::Order | RequestPriority::Cancel)
+    }
+}
+
+/// Limits on concurrent REST requests, set with [`Kalshi::with_concurrency_limit`].
+///
+/// When every slot is taken, waiting requests are started in priority order, so a
+/// heavy backfill queues behind order placement instead of competing with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcurrencyLimit {
+    /// Maximum number of requests in flight.
+    pub max_in_flight: usize,
+    /// Slots that only orders and cancels may use, so reads can never occupy every
+    /// slot.
+    pub reserved_for_trading: usize,
+}
+
+impl ConcurrencyLimit {
+    /// Allows `max_in_flight` concurrent requests, without reserved slots.
+    pub fn new(max_in_flight: usize) -> Self {
+        ConcurrencyLimit {
+            max_in_flight: max_in_flight.max(1),
+            reserved_for_trading: 0,
+        }
+    }
+
+    /// Reserves slots for orders and cancels.
+    pub fn reserved_for_trading(mut self, slots: usize) -> Self {
+        self.reserved_for_trading = slots;
+        self
+    }
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    in_flight: usize,
+    reads_in_flight: usize,
+    /// Waiting requests by priority, then arrival order.
+    waiters: BTreeMap<(RequestPriority, u64), oneshot::Sender<()>>,
+    next_waiter: u64,
+}
+
+/// Hands out request slots in priority order.
+pub(crate) struct Scheduler {
+    limit: ConcurrencyLimit,
+    state: Mutex<SchedulerState>,
+}
+
+/// A request slot, released when dropped.
+pub(crate) struct Permit {
+    scheduler: Arc<Scheduler>,
+    priority: RequestPriority,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.scheduler.release(self.priority);
+    }
+}
+
+impl Scheduler {
+    pub(crate) fn new(limit: ConcurrencyLimit) -> Self {
+        Scheduler {
+            limit,
+            state: Mutex::new(SchedulerState::default()),
+        }
+    }
+
+    /// Waits for a slot for a request of the given priority.
+    pub(crate) async fn acquire(self: &Arc<Self>, priority: RequestPriority) -> Permit {
+        let rx = {
+            let mut state = self.state.lock().unwrap();
+            let queued_ahead = state.waiters.keys().any(|(p, _)| *p <= priority);
+            if !queued_ahead && self.has_room(&state, priority) {
+                Self::start(&mut state, priority);
+                None
+            } else {
+                let (tx, rx) = oneshot::channel();
+                let id = state.next_waiter;
+                state.next_waiter += 1;
+                state.waiters.insert((priority, id), tx);
+                Some(rx)
+            }
+        };
+        if let Some(rx) = rx {
+            // If the waiting request is dropped after being granted a slot, the guard
+            // hands the slot back.
+            let mut waiting = Waiting {
+                scheduler: self,
+                priority,
+                rx: Some(rx),
+            };
+            let granted = waiting.rx.as_mut().unwrap().await;
+            waiting.rx = None;
+            if granted.is_err() {
+                // The scheduler never drops a waiter without granting it, but do not
+                // hang if it does.
+                Self::start(&mut self.state.lock().unwrap(), priority);
+            }
+        }
+        Permit {
+            scheduler: Arc::clone(self),
+            priority,
+        }
+    }
+
+    fn has_room(&self, state: &SchedulerState, priority: RequestPriority) -> bool {
+        if state.in_flight >= self.limit.max_in_flight {
+            return false;
+        }
+        priority.is_trading()
+            || state.reads_in_flight
+                < self
+                    .limit
+                    .max_in_flight
+                    .saturating_sub(self.limit.reserved_for_trading)
+    }
+
+    fn start(state: &mut SchedulerState, priority: RequestPriority) {
+        state.in_flight += 1;
+        if !priority.is_trading() {
+            state.reads_in_flight += 1;
+        }
+    }
+
+    fn release(&self, priority: RequestPriority) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= 1;
+        if !priority.is_trading() {
+            state.reads_in_flight -= 1;
+        }
+        self.grant(&mut state);
+    }
+
+    /// Starts waiting requests, highest priority first, while there is room.
+    fn grant(&self, state: &mut SchedulerState) {
+        loop {
+            let next = state
+                .waiters
+                .keys()
+                .find(|(priority, _)| self.has_room(state, *priority))
+                .copied();
+            let Some(key) = next else { return };
+            let tx = state.waiters.remove(&key).unwrap();
+            if tx.send(()).is_ok() {
+                Self::start(state, key.0);
+            }
+        }
+    }
+}
+
+/// A request waiting for a slot.
+struct Waiting<'a> {
+    scheduler: &'a Arc<Scheduler>,
+    priority: RequestPriority,
+    rx: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        if let Some(mut rx) = self.rx.take() {
+            rx.close();
+            if rx.try_recv().is_ok() {
+                self.scheduler.release(self.priority);
+            }
+        }
+    }
+}
+
+impl Kalshi {
+    /// Limits the number of concurrent REST requests and starts waiting requests by
+    /// [`RequestPriority`]. Clones of this client share the limit. Unlimited by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use kalshi::{ConcurrencyLimit, Kalshi};
+    /// # fn example(kalshi: Kalshi) -> Kalshi {
+    /// kalshi.with_concurrency_limit(ConcurrencyLimit::new(16).reserved_for_trading(4))
+    /// # }
+    /// ```
+    pub fn with_concurrency_limit(mut self, limit: ConcurrencyLimit) -> Self {
+        self.scheduler = Some(Arc::new(Scheduler::new(limit)));
+        self
+    }
+
+    /// Schedules every request of this client under `priority` instead of classifying
+    /// it, e.g. `kalshi.clone().with_request_priority(Some(RequestPriority::Backfill))`
+    /// for a task paging through trades. `None` restores automatic classification.
+    pub fn with_request_priority(mut self, priority: Option<RequestPriority>) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Waits for a request slot, if a concurrency limit is set.
+    pub(crate) async fn acquire_slot(&self, method: &Method, url: &Url) -> Option<Permit> {
+        let scheduler = self.scheduler.as_ref()?;
+        let priority = self
+            .priority
+            .unwrap_or_else(|| RequestPriority::classify(method, url));
+        Some(scheduler.acquire(priority).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    fn in_flight(scheduler: &Scheduler) -> (usize, usize, usize) {
+        let state = scheduler.state.lock().unwrap();
+        (state.in_flight, state.reads_in_flight, state.waiters.len())
+    }
+
+    #[tokio::test]
+    async fn waiting_requests_start_in_priority_order() {
+        let scheduler = Arc::new(Scheduler::new(ConcurrencyLimit::new(1)));
+        let held = scheduler.acquire(RequestPriority::MarketData).await;
+
+        let mut backfill = Box::pin(scheduler.acquire(RequestPriority::Backfill));
+        let mut read = Box::pin(scheduler.acquire(RequestPriority::MarketData));
+        let mut order = Box::pin(scheduler.acquire(RequestPriority::Order));
+        assert!(backfill.as_mut().now_or_never().is_none());
+        assert!(read.as_mut().now_or_never().is_none());
+        assert!(order.as_mut().now_or_never().is_none());
+
+        drop(held);
+        assert!(backfill.as_mut().now_or_never().is_none());
+        assert!(read.as_mut().now_or_never().is_none());
+        let order = order.as_mut().now_or_never().unwrap();
+
+        drop(order);
+        assert!(backfill.as_mut().now_or_never().is_none());
+        let read = read.as_mut().now_or_never().unwrap();
+
+        drop(read);
+        let backfill = backfill.as_mut().now_or_never().unwrap();
+        drop(backfill);
+        assert_eq!(in_flight(&scheduler), (0, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn reserved_slots_only_go_to_trading_and_are_released() {
+        let limit = ConcurrencyLimit::new(2).reserved_for_trading(1);
+        let scheduler = Arc::new(Scheduler::new(limit));
+        let first_read = scheduler.acquire(RequestPriority::MarketData).await;
+
+        let mut second_read = Box::pin(scheduler.acquire(RequestPriority::MarketData));
+        assert!(second_read.as_mut().now_or_never().is_none());
+        // A trading request jumps the waiting read and takes the reserved slot.
+        let order = scheduler
+            .acquire(RequestPriority::Order)
+            .now_or_never()
+            .unwrap();
+        assert_eq!(in_flight(&scheduler), (2, 1, 1));
+
+        // Freeing the reserved slot does not let the read in.
+        drop(order);
+        assert!(second_read.as_mut().now_or_never().is_none());
+        assert_eq!(in_flight(&scheduler), (1, 1, 1));
+
+        drop(first_read);
+        let second_read = second_read.as_mut().now_or_never().unwrap();
+        assert_eq!(in_flight(&scheduler), (1, 1, 0));
+        drop(second_read);
+        assert_eq!(in_flight(&scheduler), (0, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn cancelled_waiters_do_not_hold_slots() {
+        let scheduler = Arc::new(Scheduler::new(ConcurrencyLimit::new(1)));
+        let held = scheduler.acquire(RequestPriority::MarketData).await;
+
+        // Dropped while still queued.
+        let mut queued = Box::pin(scheduler.acquire(RequestPriority::MarketData));
+        assert!(queued.as_mut().now_or_never().is_none());
+        drop(queued);
+
+        // Dropped after being granted a slot, but before noticing it.
+        let mut granted = Box::pin(scheduler.acquire(RequestPriority::Backfill));
+        assert!(granted.as_mut().now_or_never().is_none());
+        drop(held);
+        assert_eq!(in_flight(&scheduler), (1, 1, 0));
+        drop(granted);
+        assert_eq!(in_flight(&scheduler), (0, 0, 0));
+
+        let next = scheduler
+            .acquire(RequestPriority::MarketData)
+            .now_or_never()
+            .unwrap();
+        drop(next);
+    }
+}
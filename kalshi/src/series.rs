@@ -1,30 +1,50 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{collections::HashMap, time::Duration};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver},
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+#[cfg(not(target_arch = "wasm32"))]
+use tracing::warn;
 
 impl Kalshi {
-    /// Retrieves a list of series filtered by category, with optional product metadata and tags.
+    /// Retrieves a list of series filtered by categories and tags, with optional product metadata.
     ///
     /// Maps to GET /series.
     ///
+    /// The endpoint only accepts a single category per request, so one request is made for
+    /// each entry in `categories` and the results are merged, dropping series that appear in
+    /// more than one category. An empty `categories` list queries every category at once.
+    /// The endpoint is not cursor-paginated; each request returns every matching series.
+    ///
     /// # Arguments
-    /// * `category` - Category to filter series by (required by API).
+    /// * `categories` - Categories to include; empty means all categories.
+    /// * `tags` - Tags to filter by (series containing at least one tag will be returned); empty means no tag filter.
     /// * `include_product_metadata` - If true, includes internal product metadata for each series.
-    /// * `tags` - Comma-separated list of tags to filter by (series containing at least one tag will be returned).
+    /// * `include_volume` - If true, includes the total volume traded across each series.
+    /// * `min_updated_ts` - Only return series whose metadata changed after this Unix timestamp.
     ///
     /// # Returns
     /// - `Ok(Vec<crate::Series>)`: A vector of Series matching the filters.
-    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    /// - `Err(KalshiError)`: If any of the requests fail or response parsing fails.
     ///
     /// # Example
     /// ```
-    /// /dev/null/example.rs#L1-13
     /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
     /// let series = k
     ///     .get_series_list(
-    ///         "economics".to_string(),
+    ///         vec!["Economics".to_string(), "Financials".to_string()],
+    ///         vec!["Employment".to_string(), "Inflation".to_string()],
     ///         Some(true),
-    ///         Some("employment,inflation".to_string()),
+    ///         None,
+    ///         None,
     ///     )
     ///     .await?;
     /// tracing::debug!("Found {} series", series.len());
@@ -33,18 +53,78 @@ impl Kalshi {
     /// ```
     pub async fn get_series_list(
         &self,
-        category: String,
+        categories: Vec<String>,
+        tags: Vec<String>,
         include_product_metadata: Option<bool>,
-        tags: Option<String>,
+        include_volume: Option<bool>,
+        min_updated_ts: Option<i64>,
     ) -> Result<Vec<crate::Series>, KalshiError> {
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(3);
-        // API requires category
-        params.push(("category", category));
-        add_param!(params, "include_product_metadata", include_product_metadata);
-        add_param!(params, "tags", tags);
+        let tags = (!tags.is_empty()).then(|| tags.join(","));
+        let categories: Vec<Option<String>> = if categories.is_empty() {
+            vec![None]
+        } else {
+            categories.into_iter().map(Some).collect()
+        };
+
+        let mut seen = HashSet::new();
+        let mut series = Vec::new();
+        for category in categories {
+            let mut params: Vec<(&str, String)> = Vec::with_capacity(5);
+            add_param!(params, "category", category);
+            add_param!(params, "tags", tags.as_ref());
+            add_param!(params, "include_product_metadata", include_product_metadata);
+            add_param!(params, "include_volume", include_volume);
+            add_param!(params, "min_updated_ts", min_updated_ts);
 
-        let url = self.build_url_with_params("/series", params)?;
-        let resp: SeriesListResponse = self.http_get(url).await?;
+            let url = self.build_url_with_params("/series", params)?;
+            let resp: SeriesListResponse = self.http_get(url).await?;
+            series.extend(
+                resp.series
+                    .into_iter()
+                    .filter(|s| seen.insert(s.ticker.clone())),
+            );
+        }
+        Ok(series)
+    }
+
+    /// Retrieves a single series by its ticker.
+    ///
+    /// Maps to GET /series/{series_ticker}.
+    ///
+    /// A series is the template shared by recurring events (e.g. a monthly jobs report);
+    /// the returned [`Series`](crate::Series) carries its frequency, settlement sources,
+    /// fee type, and fee multiplier.
+    ///
+    /// # Arguments
+    /// * `series_ticker` - Ticker of the series to retrieve.
+    ///
+    /// # Returns
+    /// - `Ok(crate::Series)`: The requested series.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// let series = k.get_series("KXHIGHNY").await?;
+    /// tracing::debug!("{} settles {} with fee type {}", series.title, series.frequency, series.fee_type);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_series(&self, series_ticker: &str) -> Result<crate::Series, KalshiError> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return cache
+                .series
+                .get_or_fetch(series_ticker.to_string(), self.fetch_series(series_ticker))
+                .await;
+        }
+        self.fetch_series(series_ticker).await
+    }
+
+    async fn fetch_series(&self, series_ticker: &str) -> Result<crate::Series, KalshiError> {
+        let path = format!("/series/{}", series_ticker);
+        let url = self.build_url(&path)?;
+        let resp: SeriesResponse = self.http_get(url).await?;
         Ok(resp.series)
     }
 
@@ -73,19 +153,103 @@ impl Kalshi {
         Ok(resp.series_fee_change_arr)
     }
 
+    /// Spawns a background task that polls [`get_series_fee_changes`](Kalshi::get_series_fee_changes)
+    /// and reports fee changes as they are scheduled and as they take effect.
+    ///
+    /// Each fee change is reported once as [`SeriesFeeChangeEvent::Scheduled`] when it first
+    /// appears and once as [`SeriesFeeChangeEvent::Effective`] when its `scheduled_ts` passes.
+    /// Changes already present on the first poll are reported as scheduled too, so the
+    /// receiver starts with a complete picture. Failed polls are logged and retried on the
+    /// next tick.
+    ///
+    /// # Arguments
+    /// * `series_tickers` - Series to watch; an empty set watches every series.
+    /// * `poll_interval` - Time between polls.
+    ///
+    /// # Returns
+    /// A receiver for the notifications and the handle of the polling task. The task stops
+    /// on its own once the receiver is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) {
+    /// use kalshi::SeriesFeeChangeEvent;
+    /// use std::time::Duration;
+    ///
+    /// let watched = ["KXHIGHNY".to_string()].into_iter().collect();
+    /// let (mut rx, _task) = k.watch_series_fee_changes(watched, Duration::from_secs(300));
+    /// while let Some(event) = rx.recv().await {
+    ///     match event {
+    ///         SeriesFeeChangeEvent::Scheduled(change) => tracing::info!("upcoming: {:?}", change),
+    ///         SeriesFeeChangeEvent::Effective(change) => tracing::info!("now live: {:?}", change),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn watch_series_fee_changes(
+        &self,
+        series_tickers: HashSet<String>,
+        poll_interval: Duration,
+    ) -> (UnboundedReceiver<SeriesFeeChangeEvent>, JoinHandle<()>) {
+        let (tx, rx) = unbounded_channel();
+        let kalshi = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut announced: HashSet<String> = HashSet::new();
+            let mut pending: HashMap<String, SeriesFeeChange> = HashMap::new();
+            let mut ticker = interval(poll_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if tx.is_closed() {
+                    break;
+                }
+                match kalshi.get_series_fee_changes().await {
+                    Ok(changes) => {
+                        for change in changes {
+                            let watched = series_tickers.is_empty()
+                                || series_tickers.contains(&change.series_ticker);
+                            if watched && announced.insert(change.id.clone()) {
+                                pending.insert(change.id.clone(), change.clone());
+                                if tx.send(SeriesFeeChangeEvent::Scheduled(change)).is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => warn!("Series fee change poll failed: {}", e),
+                }
+
+                let now = Utc::now();
+                let effective: Vec<String> = pending
+                    .values()
+                    .filter(|change| change.scheduled_ts <= now)
+                    .map(|change| change.id.clone())
+                    .collect();
+                for id in effective {
+                    if let Some(change) = pending.remove(&id) {
+                        if tx.send(SeriesFeeChangeEvent::Effective(change)).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+        (rx, handle)
+    }
+
     /// Retrieves OHLC candlestick history for a specific market scoped under a series.
     ///
     /// Maps to GET /series/{series_ticker}/markets/{ticker}/candlesticks.
     ///
-    /// The `period_interval` determines the time length of each candlestick and must be one of:
-    /// 1 (1 minute), 60 (1 hour), or 1440 (1 day).
+    /// The `period_interval` determines the time length of each candlestick.
     ///
     /// # Arguments
     /// * `series_ticker` - The series that contains the target market.
     /// * `market_ticker` - Market ticker (unique identifier for the market).
     /// * `start_ts` - Start timestamp (Unix seconds). Candles ending on/after this time are included.
     /// * `end_ts` - End timestamp (Unix seconds). Candles ending on/before this time are included.
-    /// * `period_interval` - Length of each candlestick in minutes (1, 60, 1440).
+    /// * `period_interval` - Length of each candlestick.
     ///
     /// # Returns
     /// - `Ok((String, Vec<crate::MarketCandlestick>))`: Market ticker and list of candlesticks.
@@ -95,13 +259,15 @@ impl Kalshi {
     /// ```
     /// /dev/null/example.rs#L1-13
     /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::PeriodInterval;
+    ///
     /// let (ticker, candles) = k
     ///     .get_market_candlesticks(
-    ///         &"JOBS-URATE".to_string(),
-    ///         &"JOBS-URATE-24NOV".to_string(),
+    ///         "JOBS-URATE",
+    ///         "JOBS-URATE-24NOV",
     ///         1_700_000_000,
     ///         1_700_086_400,
-    ///         60,
+    ///         PeriodInterval::OneHour,
     ///     )
     ///     .await?;
     /// tracing::debug!("{} candles returned for {}", candles.len(), ticker);
@@ -110,11 +276,11 @@ impl Kalshi {
     /// ```
     pub async fn get_market_candlesticks(
         &self,
-        series_ticker: &String,
-        market_ticker: &String,
+        series_ticker: &str,
+        market_ticker: &str,
         start_ts: i64,
         end_ts: i64,
-        period_interval: i64,
+        period_interval: crate::PeriodInterval,
     ) -> Result<(String, Vec<crate::MarketCandlestick>), KalshiError> {
         let path = format!(
             "/series/{}/markets/{}/candlesticks",
@@ -130,11 +296,102 @@ impl Kalshi {
         let resp: MarketCandlesticksResponse = self.http_get(url).await?;
         Ok((resp.ticker, resp.candlesticks))
     }
+
+    /// Retrieves candlesticks for an arbitrarily long range by splitting it into
+    /// sequential requests that each stay within the API's per-request limit.
+    ///
+    /// Each request covers at most [`MAX_CANDLESTICKS_PER_REQUEST`] periods. The results
+    /// are stitched together in chronological order, with any candlestick that appears
+    /// on both sides of a chunk boundary kept only once.
+    ///
+    /// # Arguments
+    /// * `series_ticker` - The series that contains the target market.
+    /// * `market_ticker` - Market ticker (unique identifier for the market).
+    /// * `start_ts` - Start timestamp (Unix seconds).
+    /// * `end_ts` - End timestamp (Unix seconds).
+    /// * `period_interval` - Length of each candlestick.
+    ///
+    /// # Returns
+    /// - `Ok((String, Vec<crate::MarketCandlestick>))`: Market ticker and every candlestick in the range.
+    /// - `Err(KalshiError)`: If any of the requests fail; candlesticks from earlier chunks are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(k: &kalshi::Kalshi) -> Result<(), kalshi::KalshiError> {
+    /// use kalshi::PeriodInterval;
+    ///
+    /// // A year of minute bars takes roughly 100 requests.
+    /// let (_, candles) = k
+    ///     .get_market_candlesticks_chunked(
+    ///         "JOBS-URATE",
+    ///         "JOBS-URATE-24NOV",
+    ///         1_668_000_000,
+    ///         1_700_000_000,
+    ///         PeriodInterval::OneMinute,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_market_candlesticks_chunked(
+        &self,
+        series_ticker: &str,
+        market_ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+        period_interval: crate::PeriodInterval,
+    ) -> Result<(String, Vec<crate::MarketCandlestick>), KalshiError> {
+        if start_ts > end_ts {
+            return Err(KalshiError::UserInputError(format!(
+                "start_ts ({}) must not be after end_ts ({})",
+                start_ts, end_ts
+            )));
+        }
+        let chunk_seconds = MAX_CANDLESTICKS_PER_REQUEST * period_interval.as_minutes() * 60;
+        let series_ticker = series_ticker.to_string();
+        let market_ticker = market_ticker.to_string();
+
+        let mut ticker = market_ticker.clone();
+        let mut candlesticks: Vec<crate::MarketCandlestick> = Vec::new();
+        let mut chunk_start = start_ts;
+        while chunk_start <= end_ts {
+            let chunk_end = chunk_start.saturating_add(chunk_seconds).min(end_ts);
+            let (resp_ticker, chunk) = self
+                .get_market_candlesticks(
+                    &series_ticker,
+                    &market_ticker,
+                    chunk_start,
+                    chunk_end,
+                    period_interval,
+                )
+                .await?;
+            ticker = resp_ticker;
+            let last_ts = candlesticks.last().map(|c| c.end_period_ts);
+            candlesticks.extend(
+                chunk
+                    .into_iter()
+                    .filter(|c| last_ts.map_or(true, |last| c.end_period_ts > last)),
+            );
+            match chunk_end.checked_add(1) {
+                Some(next) => chunk_start = next,
+                None => break,
+            }
+        }
+        Ok((ticker, candlesticks))
+    }
 }
 
+/// Maximum number of candlesticks the API returns for a single market candlestick request.
+pub const MAX_CANDLESTICKS_PER_REQUEST: i64 = 5000;
+
 // PRIVATE RESPONSES
 // -----------------------------------------------
 
+#[derive(Debug, Deserialize, Serialize)]
+struct SeriesResponse {
+    series: crate::Series,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct SeriesListResponse {
     series: Vec<crate::Series>,
@@ -155,7 +412,7 @@ struct MarketCandlesticksResponse {
 // -----------------------------------------------
 
 /// A scheduled fee change for a series.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SeriesFeeChange {
     /// ID of this scheduled fee change.
     pub id: String,
@@ -165,6 +422,15 @@ pub struct SeriesFeeChange {
     pub fee_type: String,
     /// Multiplier applied to the fee calculations.
     pub fee_multiplier: f64,
-    /// Scheduled time (RFC3339).
-    pub scheduled_ts: String,
-}
\ No newline at end of file
+    /// Time at which the new fee takes effect.
+    pub scheduled_ts: DateTime<Utc>,
+}
+
+/// Notifications emitted by [`Kalshi::watch_series_fee_changes`].
+#[derive(Debug, Clone)]
+pub enum SeriesFeeChangeEvent {
+    /// A fee change was announced for a watched series.
+    Scheduled(SeriesFeeChange),
+    /// A previously announced fee change has reached its scheduled time.
+    Effective(SeriesFeeChange),
+}
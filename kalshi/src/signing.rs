@@ -0,0 +1,119 @@
+//! RSA-PSS request signing, backed by OpenSSL or by the pure-Rust `rsa` crate depending
+//! on the enabled features.
+
+use std::error::Error;
+
+#[cfg(not(any(feature = "openssl-signing", feature = "rust-signing")))]
+compile_error!("either the `openssl-signing` or the `rust-signing` feature must be enabled");
+
+/// A loaded private key that signs API requests with RSA-PSS and SHA-256.
+///
+/// Uses the pure-Rust `rsa` crate when the `rust-signing` feature is enabled, and
+/// OpenSSL otherwise.
+pub struct RequestSigner {
+    #[cfg(feature = "rust-signing")]
+    key: rsa::pss::BlindedSigningKey<sha2::Sha256>,
+    #[cfg(all(feature = "openssl-signing", not(feature = "rust-signing")))]
+    key: openssl::pkey::PKey<openssl::pkey::Private>,
+}
+
+impl RequestSigner {
+    /// Returns whether a PEM encoded key is passphrase-protected.
+    pub fn is_encrypted_pem(pem: &str) -> bool {
+        pem.contains("BEGIN ENCRYPTED PRIVATE KEY") || pem.contains("Proc-Type: 4,ENCRYPTED")
+    }
+}
+
+impl std::fmt::Debug for RequestSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestSigner").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rust-signing")]
+impl RequestSigner {
+    /// Loads a PEM encoded RSA private key, in either PKCS#1 or PKCS#8 form.
+    pub fn from_pem(pem: &str) -> Result<Self, Box<dyn Error>> {
+        use rsa::{pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey, RsaPrivateKey};
+
+        let key =
+            RsaPrivateKey::from_pkcs1_pem(pem).or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))?;
+        Ok(RequestSigner {
+            key: rsa::pss::BlindedSigningKey::new(key),
+        })
+    }
+
+    /// Loads a passphrase-protected PEM encoded RSA private key.
+    ///
+    /// Only encrypted PKCS#8 (`BEGIN ENCRYPTED PRIVATE KEY`) is supported by this
+    /// backend; legacy encrypted PKCS#1 keys need the `openssl-signing` backend or
+    /// converting with `openssl pkcs8 -topk8`.
+    pub fn from_encrypted_pem(pem: &str, passphrase: &[u8]) -> Result<Self, Box<dyn Error>> {
+        use rsa::{pkcs8::DecodePrivateKey, RsaPrivateKey};
+
+        let key = RsaPrivateKey::from_pkcs8_encrypted_pem(pem, passphrase)?;
+        Ok(RequestSigner {
+            key: rsa::pss::BlindedSigningKey::new(key),
+        })
+    }
+
+    /// Signs `msg`, returning the raw signature bytes.
+    pub fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        use rsa::signature::{RandomizedSigner, SignatureEncoding};
+
+        let signature = self.key.try_sign_with_rng(&mut rand_core::OsRng, msg)?;
+        Ok(signature.to_vec())
+    }
+}
+
+#[cfg(all(feature = "openssl-signing", not(feature = "rust-signing")))]
+impl RequestSigner {
+    /// Loads a PEM encoded RSA private key, in either PKCS#1 or PKCS#8 form.
+    pub fn from_pem(pem: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(RequestSigner {
+            key: openssl::pkey::PKey::private_key_from_pem(pem.as_bytes())?,
+        })
+    }
+
+    /// Loads a passphrase-protected PEM encoded RSA private key, either encrypted PKCS#8
+    /// or legacy encrypted PKCS#1.
+    pub fn from_encrypted_pem(pem: &str, passphrase: &[u8]) -> Result<Self, Box<dyn Error>> {
+        Ok(RequestSigner {
+            key: openssl::pkey::PKey::private_key_from_pem_passphrase(pem.as_bytes(), passphrase)?,
+        })
+    }
+
+    /// Signs `msg`, returning the raw signature bytes.
+    pub fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        use openssl::{
+            hash::MessageDigest,
+            rsa::Padding,
+            sign::{RsaPssSaltlen, Signer},
+        };
+
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.key)?;
+        signer.set_rsa_padding(Padding::PKCS1_PSS)?;
+        signer.set_rsa_mgf1_md(MessageDigest::sha256())?;
+        signer.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)?;
+        Ok(signer.sign_oneshot_to_vec(msg)?)
+    }
+}
+
+/// The inputs and output of signing one request, returned by
+/// [`Kalshi::debug_signature`](crate::Kalshi::debug_signature).
+///
+/// Kalshi signs the concatenation of the millisecond timestamp, the HTTP method and the
+/// URL path without its query string. Comparing `message` against that scheme is the
+/// quickest way to diagnose a 401 caused by a bad signature.
+#[derive(Debug, Clone)]
+pub struct SignedRequest {
+    /// Timestamp in milliseconds since the epoch, including any clock offset.
+    pub timestamp: i64,
+    pub method: String,
+    /// Path that was signed, e.g. `/trade-api/v2/portfolio/balance`.
+    pub path: String,
+    /// The exact message that was signed.
+    pub message: String,
+    /// Authentication headers sent with the request.
+    pub headers: Vec<(&'static str, String)>,
+}
@@ -0,0 +1,92 @@
+use super::Kalshi;
+use crate::kalshi_error::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl Kalshi {
+    /// Retrieves structured targets with optional filters.
+    ///
+    /// Structured targets are the underlying real-world entities (teams, candidates,
+    /// economic series) that markets are written against.
+    ///
+    /// Maps to GET /structured_targets
+    ///
+    /// # Arguments
+    /// * `type_` - Optional structured target type filter.
+    /// * `competition` - Optional competition filter.
+    /// * `page_size` - Optional number of items per page (1-2000, API default 100).
+    /// * `cursor` - Optional pagination cursor from a previous response.
+    ///
+    /// # Returns
+    /// - `Ok((Vec<StructuredTarget>, Option<String>))`: A page of targets and the cursor for the next page.
+    /// - `Err(KalshiError)`: If the request fails or response parsing fails.
+    pub async fn get_structured_targets(
+        &self,
+        type_: Option<String>,
+        competition: Option<String>,
+        page_size: Option<i32>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<StructuredTarget>, Option<String>), KalshiError> {
+        let mut params = Vec::new();
+        add_param!(params, "type", type_);
+        add_param!(params, "competition", competition);
+        add_param!(params, "page_size", page_size);
+        add_param!(params, "cursor", cursor);
+
+        let url = self.build_url_with_params("/structured_targets", params)?;
+        let resp: GetStructuredTargetsResponse = self.http_get(url).await?;
+        // The API signals the last page with an empty cursor rather than omitting it.
+        let cursor = resp.cursor.filter(|c| !c.is_empty());
+        Ok((resp.structured_targets, cursor))
+    }
+
+    /// Retrieves a single structured target by its ID.
+    ///
+    /// Maps to GET /structured_targets/{structured_target_id}
+    pub async fn get_structured_target(
+        &self,
+        structured_target_id: &str,
+    ) -> Result<StructuredTarget, KalshiError> {
+        let path = format!("/structured_targets/{}", structured_target_id);
+        let url = self.build_url(&path)?;
+        let resp: GetStructuredTargetResponse = self.http_get(url).await?;
+        Ok(resp.structured_target)
+    }
+}
+
+// PRIVATE RESPONSES
+// -----------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct GetStructuredTargetsResponse {
+    #[serde(default)]
+    pub structured_targets: Vec<StructuredTarget>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetStructuredTargetResponse {
+    pub structured_target: StructuredTarget,
+}
+
+// PUBLIC STRUCTS
+// -----------------------------------------------
+
+/// A real-world entity that markets reference, such as a team, politician, or economic series.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StructuredTarget {
+    /// Unique identifier for the structured target.
+    pub id: String,
+    /// Name of the structured target.
+    pub name: Option<String>,
+    /// Type of the structured target (e.g. a team or a person).
+    pub r#type: Option<String>,
+    /// Type-specific details about the target.
+    pub details: Option<serde_json::Value>,
+    /// External source identifier, if available.
+    pub source_id: Option<String>,
+    /// Source ids keyed by provider, if available.
+    pub source_ids: Option<HashMap<String, String>>,
+    /// Last time this structured target was updated (RFC3339).
+    pub last_updated_ts: Option<String>,
+}
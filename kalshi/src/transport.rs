@@ -0,0 +1,100 @@
+use crate::kalshi_error::*;
+#[cfg(not(target_arch = "wasm32"))]
+use futures_util::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures_util::future::LocalBoxFuture as BoxFuture;
+use reqwest::{header::HeaderMap, Method, StatusCode, Url};
+
+/// `Send` everywhere except on wasm32, where futures run on the browser's single
+/// thread and the fetch futures are not `Send`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// `Send` everywhere except on wasm32, where futures run on the browser's single
+/// thread and the fetch futures are not `Send`.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// A signed request ready to be sent by an [`HttpTransport`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    /// Authentication and content headers, already applied by the client.
+    pub headers: HeaderMap,
+    /// JSON encoded body, if the request has one.
+    pub body: Option<Vec<u8>>,
+}
+
+/// A response returned by an [`HttpTransport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Sends the HTTP requests of a [`Kalshi`](crate::Kalshi) client.
+///
+/// Every REST call goes through the transport after it has been signed, so swapping it
+/// with [`Kalshi::with_transport`](crate::Kalshi::with_transport) lets tests serve
+/// canned or recorded responses without network access. Non-success statuses should be
+/// returned as responses, not errors; the client turns them into errors itself.
+///
+/// Implemented for `reqwest::Client`, which is the default, and for
+/// `reqwest_middleware::ClientWithMiddleware` with the `middleware` feature.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait HttpTransport: Send + Sync {
+    /// Sends a request and reads the whole response body.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, KalshiError>>;
+}
+
+/// Sends the HTTP requests of a [`Kalshi`](crate::Kalshi) client.
+///
+/// On wasm32 requests run on the browser's single thread, so transports and their
+/// futures do not need to be `Send`.
+#[cfg(target_arch = "wasm32")]
+pub trait HttpTransport {
+    /// Sends a request and reads the whole response body.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, KalshiError>>;
+}
+
+/// Implements [`HttpTransport`] for a reqwest-style client, whose request builder and
+/// response share reqwest's API.
+macro_rules! impl_reqwest_transport {
+    ($client:ty) => {
+        impl HttpTransport for $client {
+            fn send(
+                &self,
+                request: HttpRequest,
+            ) -> BoxFuture<'_, Result<HttpResponse, KalshiError>> {
+                Box::pin(async move {
+                    let mut builder = self
+                        .request(request.method, request.url)
+                        .headers(request.headers);
+                    if let Some(body) = request.body {
+                        builder = builder.body(body);
+                    }
+                    let resp = builder.send().await?;
+                    let status = resp.status();
+                    let headers = resp.headers().clone();
+                    let body = resp.bytes().await?.to_vec();
+                    Ok(HttpResponse {
+                        status,
+                        headers,
+                        body,
+                    })
+                })
+            }
+        }
+    };
+}
+
+impl_reqwest_transport!(reqwest::Client);
+
+#[cfg(all(feature = "middleware", not(target_arch = "wasm32")))]
+impl_reqwest_transport!(reqwest_middleware::ClientWithMiddleware);
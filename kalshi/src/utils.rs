@@ -1,13 +1,9 @@
-use std::{
-    error::Error,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::error::Error;
 
 use base64::{prelude::BASE64_STANDARD, Engine};
-use openssl::sign::Signer;
 use reqwest::Method;
 
-use crate::TradingEnvironment;
+use crate::{RequestSigner, SignedRequest, TradingEnvironment};
 // MACROS
 
 #[macro_export]
@@ -40,21 +36,42 @@ pub const fn build_ws_url(trading_env: TradingEnvironment) -> &'static str {
 
 pub(super) fn api_key_headers(
     key_id: impl AsRef<str>,
-    signer: &mut Signer,
+    signer: &RequestSigner,
     path: impl AsRef<str>,
     method: Method,
+    clock_offset_ms: i64,
 ) -> Result<Vec<(&'static str, String)>, Box<dyn Error>> {
+    let signed = sign_request(key_id, signer, path, method, clock_offset_ms)?;
+    tracing::trace!(signing_string = %signed.message, "Signed request");
+    Ok(signed.headers)
+}
+
+pub(super) fn sign_request(
+    key_id: impl AsRef<str>,
+    signer: &RequestSigner,
+    path: impl AsRef<str>,
+    method: Method,
+    clock_offset_ms: i64,
+) -> Result<SignedRequest, Box<dyn Error>> {
     let mut headers = Vec::new();
-    let ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    // Shift the local clock by the offset measured against the exchange, if any.
+    // chrono reads the clock through JavaScript on wasm32, where SystemTime is unavailable.
+    let ts = chrono::Utc::now().timestamp_millis() + clock_offset_ms;
     let method = method.as_str();
     let path = path.as_ref();
     let msg_string = format!("{ts}{method}{path}");
     // Raw bytes of signature
-    let sig_raw = signer.sign_oneshot_to_vec(msg_string.as_bytes())?;
+    let sig_raw = signer.sign(msg_string.as_bytes())?;
     // base64 encoded sig string
     let sig: String = BASE64_STANDARD.encode(sig_raw);
     headers.push(("kalshi-access-key", key_id.as_ref().to_string()));
     headers.push(("kalshi-access-signature", sig));
     headers.push(("kalshi-access-timestamp", ts.to_string()));
-    Ok(headers)
+    Ok(SignedRequest {
+        timestamp: ts,
+        method: method.to_string(),
+        path: path.to_string(),
+        message: msg_string,
+        headers,
+    })
 }
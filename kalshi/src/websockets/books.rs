@@ -0,0 +1,193 @@
+use super::{
+    channels::OrderbookUpdate,
+    client::KalshiWebsocketClient,
+    responses::{KalshiOrderbookDeltaMessage, KalshiOrderbookSnapshotMessage, KalshiSide},
+};
+use arc_swap::ArcSwapOption;
+use futures_util::StreamExt;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, RwLock},
+};
+use tokio::task::JoinHandle;
+
+/// An immutable view of one market's orderbook.
+///
+/// Both sides hold resting bids as price in cents to contracts. A YES ask is the
+/// complement of a NO bid, so the best YES ask is `100 - ` the best NO bid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderbookState {
+    pub market_ticker: String,
+    pub yes: BTreeMap<u32, u32>,
+    pub no: BTreeMap<u32, u32>,
+    /// Sequence number of the last snapshot or delta applied.
+    pub seq: u32,
+}
+
+impl OrderbookState {
+    fn from_snapshot(seq: u32, msg: &KalshiOrderbookSnapshotMessage) -> Self {
+        let levels = |side: &Option<Vec<(u32, u32)>>| {
+            side.iter()
+                .flatten()
+                .filter(|(_, count)| *count > 0)
+                .copied()
+                .collect()
+        };
+        OrderbookState {
+            market_ticker: msg.market_ticker.clone(),
+            yes: levels(&msg.yes),
+            no: levels(&msg.no),
+            seq,
+        }
+    }
+
+    fn apply_delta(&mut self, seq: u32, msg: &KalshiOrderbookDeltaMessage) {
+        let side = match msg.side {
+            KalshiSide::Yes => &mut self.yes,
+            KalshiSide::No => &mut self.no,
+        };
+        let count = side.get(&msg.price).copied().unwrap_or(0) as i64 + msg.delta as i64;
+        if count > 0 {
+            side.insert(msg.price, count as u32);
+        } else {
+            side.remove(&msg.price);
+        }
+        self.seq = seq;
+    }
+
+    /// Returns the highest YES bid as `(price, contracts)`.
+    pub fn best_yes_bid(&self) -> Option<(u32, u32)> {
+        self.yes.last_key_value().map(|(p, c)| (*p, *c))
+    }
+
+    /// Returns the highest NO bid as `(price, contracts)`.
+    pub fn best_no_bid(&self) -> Option<(u32, u32)> {
+        self.no.last_key_value().map(|(p, c)| (*p, *c))
+    }
+
+    /// Returns the lowest YES ask as `(price, contracts)`, implied by the best NO bid.
+    pub fn best_yes_ask(&self) -> Option<(u32, u32)> {
+        self.best_no_bid().map(|(p, c)| (100 - p, c))
+    }
+
+    /// Returns the lowest NO ask as `(price, contracts)`, implied by the best YES bid.
+    pub fn best_no_ask(&self) -> Option<(u32, u32)> {
+        self.best_yes_bid().map(|(p, c)| (100 - p, c))
+    }
+}
+
+/// A cheap, lock-free read handle on one market's book.
+///
+/// Every update publishes a new [`OrderbookState`], so a loaded state never changes
+/// underneath its reader. Clones share the same book.
+#[derive(Debug, Clone, Default)]
+pub struct BookHandle {
+    current: Arc<ArcSwapOption<OrderbookState>>,
+}
+
+impl BookHandle {
+    /// Returns the current book, or `None` until a snapshot arrives and while the book
+    /// is being resynced.
+    pub fn load(&self) -> Option<Arc<OrderbookState>> {
+        self.current.load_full()
+    }
+}
+
+/// Orderbooks maintained from the `orderbook_delta` channel, shared through
+/// [`BookHandle`]s.
+///
+/// Writers apply updates copy-on-write and readers never block them, which suits many
+/// tasks polling best bid and ask in a quoting loop. Look a handle up once with
+/// [`handle`](SharedOrderbooks::handle) and keep it; only the lookup takes a lock.
+///
+/// Clones share the same books.
+///
+/// # Example
+/// ```
+/// # fn example(ws: &kalshi::client::KalshiWebsocketClient) {
+/// use kalshi::books::SharedOrderbooks;
+///
+/// let books = SharedOrderbooks::new();
+/// books.spawn(ws);
+/// let handle = books.handle("INXD-24DEC31-B5000");
+/// if let Some(book) = handle.load() {
+///     println!("{:?} / {:?}", book.best_yes_bid(), book.best_yes_ask());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedOrderbooks {
+    books: Arc<RwLock<HashMap<String, BookHandle>>>,
+}
+
+impl SharedOrderbooks {
+    /// Creates an empty set of books.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns a task that applies every orderbook message received by `client`.
+    ///
+    /// The task ends once the websocket client shuts down.
+    pub fn spawn(&self, client: &KalshiWebsocketClient) -> JoinHandle<()> {
+        let books = self.clone();
+        let mut updates = client.split_channels().orderbooks;
+        tokio::spawn(async move {
+            while let Some(update) = updates.next().await {
+                if let Ok(update) = update {
+                    books.apply(&update);
+                }
+            }
+        })
+    }
+
+    /// Applies an orderbook message.
+    ///
+    /// Deltas for a market without a snapshot are ignored. A resync clears the books
+    /// of its markets until their new snapshots arrive.
+    pub fn apply(&self, update: &OrderbookUpdate) {
+        match update {
+            OrderbookUpdate::Snapshot { seq, msg, .. } => {
+                let state = OrderbookState::from_snapshot(*seq, msg);
+                self.handle(&msg.market_ticker)
+                    .current
+                    .store(Some(Arc::new(state)));
+            }
+            OrderbookUpdate::Delta { seq, msg, .. } => {
+                let handle = self.handle(&msg.market_ticker);
+                if let Some(current) = handle.load() {
+                    let mut state = (*current).clone();
+                    state.apply_delta(*seq, msg);
+                    handle.current.store(Some(Arc::new(state)));
+                }
+            }
+            OrderbookUpdate::Resynced { market_tickers, .. } => {
+                for ticker in market_tickers {
+                    self.handle(ticker).current.store(None);
+                }
+            }
+        }
+    }
+
+    /// Returns the read handle for a market, creating an empty one if needed.
+    pub fn handle(&self, market_ticker: &str) -> BookHandle {
+        if let Some(handle) = self.books.read().unwrap().get(market_ticker) {
+            return handle.clone();
+        }
+        self.books
+            .write()
+            .unwrap()
+            .entry(market_ticker.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Returns the current book for a market, if one is available.
+    pub fn get(&self, market_ticker: &str) -> Option<Arc<OrderbookState>> {
+        self.books
+            .read()
+            .unwrap()
+            .get(market_ticker)
+            .and_then(|handle| handle.load())
+    }
+}
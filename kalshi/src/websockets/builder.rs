@@ -0,0 +1,143 @@
+use super::{
+    client::{
+        KalshiWebsocketClient, KeepaliveConfig, ReconnectPolicy, WatchdogConfig, WsConnectorConfig,
+        DEFAULT_BUFFER_SIZE,
+    },
+    commands::KalshiSubscribeCommandParams,
+};
+use crate::{utils, Kalshi, TradingEnvironment};
+use std::{error::Error, time::Duration};
+
+/// Configures and opens a [`KalshiWebsocketClient`].
+///
+/// Settings left unset fall back to those of the [`Kalshi`] passed to
+/// [`connect`](KalshiWsClientBuilder::connect), which itself is never modified.
+///
+/// Compression is not configurable: `tungstenite` does not support `permessage-deflate`.
+///
+/// # Example
+/// ```
+/// # async fn example(k: &kalshi::Kalshi) -> Result<(), Box<dyn std::error::Error>> {
+/// use kalshi::{client::KalshiWebsocketClient, commands::KalshiSubscribeCommandParams, KalshiChannel};
+/// use std::time::Duration;
+///
+/// let ws = KalshiWebsocketClient::builder()
+///     .reconnect(Duration::from_secs(1), Duration::from_secs(30))
+///     .buffer_size(4096)
+///     .subscribe(KalshiSubscribeCommandParams {
+///         channels: vec![KalshiChannel::Fill],
+///         ..Default::default()
+///     })
+///     .connect(k)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KalshiWsClientBuilder {
+    url: Option<String>,
+    reconnect: Option<ReconnectPolicy>,
+    buffer_size: usize,
+    keepalive: Option<KeepaliveConfig>,
+    connector: Option<WsConnectorConfig>,
+    watchdog: Option<WatchdogConfig>,
+    subscriptions: Vec<KalshiSubscribeCommandParams>,
+}
+
+impl Default for KalshiWsClientBuilder {
+    fn default() -> Self {
+        KalshiWsClientBuilder {
+            url: None,
+            reconnect: None,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            keepalive: None,
+            connector: None,
+            watchdog: None,
+            subscriptions: Vec::new(),
+        }
+    }
+}
+
+impl KalshiWsClientBuilder {
+    /// Connects to a custom websocket URL.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Connects to the websocket URL of a trading environment.
+    pub fn environment(mut self, trading_env: TradingEnvironment) -> Self {
+        self.url = Some(utils::build_ws_url(trading_env).to_string());
+        self
+    }
+
+    /// Reconnects whenever the connection drops, with exponential backoff between
+    /// `initial_backoff` and `max_backoff`. Disabled by default.
+    pub fn reconnect(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.reconnect = Some(ReconnectPolicy::new(initial_backoff, max_backoff));
+        self
+    }
+
+    /// Reconnects whenever the connection drops, following `policy`.
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    /// Sets how many messages each receiver can fall behind before it starts lagging.
+    /// Defaults to 1024.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// Sets the ping interval and liveness timeout.
+    pub fn keepalive(mut self, keepalive: KeepaliveConfig) -> Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
+    /// Sets the TLS and socket settings.
+    pub fn connector(mut self, connector: WsConnectorConfig) -> Self {
+        self.connector = Some(connector);
+        self
+    }
+
+    /// Sets the stale-feed watchdog.
+    pub fn watchdog(mut self, watchdog: WatchdogConfig) -> Self {
+        self.watchdog = Some(watchdog);
+        self
+    }
+
+    /// Adds a subscription to make as soon as the connection is open.
+    pub fn subscribe(mut self, params: KalshiSubscribeCommandParams) -> Self {
+        self.subscriptions.push(params);
+        self
+    }
+
+    /// Opens the connection and makes the configured subscriptions.
+    ///
+    /// Resolves once every subscription is acknowledged.
+    pub async fn connect(self, kalshi: &Kalshi) -> Result<KalshiWebsocketClient, Box<dyn Error>> {
+        let mut kalshi = kalshi.clone();
+        if let Some(url) = self.url {
+            kalshi = kalshi.with_ws_url(url);
+        }
+        if let Some(keepalive) = self.keepalive {
+            kalshi = kalshi.with_ws_keepalive(keepalive);
+        }
+        if let Some(connector) = self.connector {
+            kalshi = kalshi.with_ws_connector(connector);
+        }
+        if let Some(watchdog) = self.watchdog {
+            kalshi = kalshi.with_ws_watchdog(watchdog);
+        }
+        let mut client =
+            KalshiWebsocketClient::connect_inner(&mut kalshi, self.reconnect, self.buffer_size)
+                .await?;
+        for params in self.subscriptions {
+            client.subscribe(params).await?;
+        }
+        Ok(client)
+    }
+}
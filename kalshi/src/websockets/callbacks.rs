@@ -0,0 +1,185 @@
+use super::{
+    channels::OrderbookUpdate,
+    client::KalshiWebsocketClient,
+    responses::{KalshiTickerMessage, KalshiTradeMessage, KalshiWebsocketResponse},
+};
+use futures_util::StreamExt;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, RwLock,
+    },
+};
+use tokio::task::JoinHandle;
+
+/// Identifies a callback registered with [`KalshiWebsocketClient::on_ticker`],
+/// [`on_trade`](KalshiWebsocketClient::on_trade) or
+/// [`on_book_update`](KalshiWebsocketClient::on_book_update).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CallbackId(u64);
+
+type Handlers<T> = RwLock<HashMap<String, Vec<(CallbackId, Arc<dyn Fn(&T) + Send + Sync>)>>>;
+
+/// Per-market callbacks and the task that dispatches messages to them.
+#[derive(Default)]
+pub(crate) struct Callbacks {
+    next_id: AtomicU64,
+    tickers: Handlers<KalshiTickerMessage>,
+    trades: Handlers<KalshiTradeMessage>,
+    books: Handlers<OrderbookUpdate>,
+    dispatcher: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Callbacks {
+    fn register<T>(
+        &self,
+        handlers: &Handlers<T>,
+        market_ticker: &str,
+        callback: Arc<dyn Fn(&T) + Send + Sync>,
+    ) -> CallbackId {
+        let id = CallbackId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        handlers
+            .write()
+            .unwrap()
+            .entry(market_ticker.to_string())
+            .or_default()
+            .push((id, callback));
+        id
+    }
+
+    fn remove(&self, id: CallbackId) {
+        fn remove_from<T>(handlers: &Handlers<T>, id: CallbackId) {
+            handlers.write().unwrap().retain(|_, callbacks| {
+                callbacks.retain(|(cb_id, _)| *cb_id != id);
+                !callbacks.is_empty()
+            });
+        }
+        remove_from(&self.tickers, id);
+        remove_from(&self.trades, id);
+        remove_from(&self.books, id);
+    }
+
+    fn dispatch(&self, res: KalshiWebsocketResponse) {
+        match res {
+            KalshiWebsocketResponse::Ticker { msg, .. } => {
+                call(&self.tickers, &msg.market_ticker, &msg);
+            }
+            KalshiWebsocketResponse::Trade { msg, .. } => {
+                call(&self.trades, &msg.market_ticker, &msg);
+            }
+            KalshiWebsocketResponse::OrderbookSnapshot { sid, seq, msg } => {
+                let ticker = msg.market_ticker.clone();
+                call(
+                    &self.books,
+                    &ticker,
+                    &OrderbookUpdate::Snapshot { sid, seq, msg },
+                );
+            }
+            KalshiWebsocketResponse::OrderbookDelta { sid, seq, msg } => {
+                let ticker = msg.market_ticker.clone();
+                call(
+                    &self.books,
+                    &ticker,
+                    &OrderbookUpdate::Delta { sid, seq, msg },
+                );
+            }
+            KalshiWebsocketResponse::Resynced {
+                sid,
+                market_tickers,
+            } => {
+                let update = OrderbookUpdate::Resynced {
+                    sid,
+                    market_tickers: market_tickers.clone(),
+                };
+                for ticker in &market_tickers {
+                    call(&self.books, ticker, &update);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Runs the callbacks registered for `market_ticker`.
+///
+/// The callbacks are cloned out first, so a callback may register or remove callbacks.
+fn call<T>(handlers: &Handlers<T>, market_ticker: &str, msg: &T) {
+    let callbacks: Vec<_> = match handlers.read().unwrap().get(market_ticker) {
+        Some(callbacks) => callbacks.iter().map(|(_, cb)| Arc::clone(cb)).collect(),
+        None => return,
+    };
+    for callback in callbacks {
+        callback(msg);
+    }
+}
+
+impl KalshiWebsocketClient {
+    /// Calls `callback` with every ticker message for `market_ticker`.
+    ///
+    /// Callbacks run in registration order on a single background task that starts with
+    /// the first registration and ends when the client shuts down, so they should return
+    /// quickly. Subscribing to the channel is still up to the caller.
+    ///
+    /// # Example
+    /// ```
+    /// # fn example(ws: &kalshi::client::KalshiWebsocketClient) {
+    /// ws.on_ticker("INXD-24DEC31-B5000", |msg| {
+    ///     println!("{} bid {} ask {}", msg.market_ticker, msg.yes_bid, msg.yes_ask);
+    /// });
+    /// # }
+    /// ```
+    pub fn on_ticker<F>(&self, market_ticker: &str, callback: F) -> CallbackId
+    where
+        F: Fn(&KalshiTickerMessage) + Send + Sync + 'static,
+    {
+        self.start_dispatcher();
+        self.callbacks
+            .register(&self.callbacks.tickers, market_ticker, Arc::new(callback))
+    }
+
+    /// Calls `callback` with every public trade in `market_ticker`.
+    ///
+    /// See [`on_ticker`](KalshiWebsocketClient::on_ticker) for how callbacks are run.
+    pub fn on_trade<F>(&self, market_ticker: &str, callback: F) -> CallbackId
+    where
+        F: Fn(&KalshiTradeMessage) + Send + Sync + 'static,
+    {
+        self.start_dispatcher();
+        self.callbacks
+            .register(&self.callbacks.trades, market_ticker, Arc::new(callback))
+    }
+
+    /// Calls `callback` with every orderbook snapshot, delta and resync for `market_ticker`.
+    ///
+    /// See [`on_ticker`](KalshiWebsocketClient::on_ticker) for how callbacks are run.
+    pub fn on_book_update<F>(&self, market_ticker: &str, callback: F) -> CallbackId
+    where
+        F: Fn(&OrderbookUpdate) + Send + Sync + 'static,
+    {
+        self.start_dispatcher();
+        self.callbacks
+            .register(&self.callbacks.books, market_ticker, Arc::new(callback))
+    }
+
+    /// Removes a callback. Does nothing if it was already removed.
+    pub fn remove_callback(&self, id: CallbackId) {
+        self.callbacks.remove(id);
+    }
+
+    fn start_dispatcher(&self) {
+        let mut dispatcher = self.callbacks.dispatcher.lock().unwrap();
+        if dispatcher.is_some() {
+            return;
+        }
+        let callbacks = Arc::clone(&self.callbacks);
+        let mut messages = self.stream();
+        *dispatcher = Some(tokio::spawn(async move {
+            while let Some(res) = messages.next().await {
+                if let Ok(res) = res {
+                    callbacks.dispatch(res);
+                }
+            }
+        }));
+    }
+}
@@ -0,0 +1,258 @@
+use super::{
+    client::{KalshiWebsocketClient, KalshiWebsocketStream},
+    responses::{
+        KalshiFillMessage, KalshiOrderbookDeltaMessage, KalshiOrderbookSnapshotMessage,
+        KalshiTickerMessage, KalshiTradeMessage, KalshiWebsocketResponse,
+    },
+};
+use crate::KalshiError;
+use chrono::{DateTime, Utc};
+use futures_util::{
+    future,
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A message from a single channel, tagged with the sid of its subscription.
+#[derive(Debug, Clone)]
+pub struct ChannelMessage<T> {
+    pub sid: u32,
+    pub msg: T,
+}
+
+/// A message from the `orderbook_delta` channel.
+#[derive(Debug, Clone)]
+pub enum OrderbookUpdate {
+    /// Full orderbook, replacing any locally maintained book.
+    Snapshot {
+        sid: u32,
+        seq: u32,
+        msg: KalshiOrderbookSnapshotMessage,
+    },
+    /// Incremental change to the last snapshot.
+    Delta {
+        sid: u32,
+        seq: u32,
+        msg: KalshiOrderbookDeltaMessage,
+    },
+    /// The subscription skipped a sequence number and a new snapshot follows.
+    Resynced {
+        sid: u32,
+        market_tickers: Vec<String>,
+    },
+}
+
+/// A normalized market data message for a single market, whatever channel it came from.
+#[derive(Debug, Clone)]
+pub struct MarketDataEvent {
+    pub sid: u32,
+    pub ticker: String,
+    /// When Kalshi generated the message, if the message says so. Orderbook snapshots
+    /// carry no timestamp.
+    pub timestamp: Option<DateTime<Utc>>,
+    pub kind: MarketDataKind,
+}
+
+/// What a [`MarketDataEvent`] carries.
+#[derive(Debug, Clone)]
+pub enum MarketDataKind {
+    Ticker(KalshiTickerMessage),
+    Trade(KalshiTradeMessage),
+    /// Full orderbook, replacing any locally maintained book.
+    BookSnapshot {
+        seq: u32,
+        msg: KalshiOrderbookSnapshotMessage,
+    },
+    /// Incremental change to the last snapshot.
+    BookDelta {
+        seq: u32,
+        msg: KalshiOrderbookDeltaMessage,
+    },
+    /// The book skipped a sequence number and a new snapshot follows.
+    BookResynced,
+}
+
+impl MarketDataEvent {
+    /// Converts a websocket message into market data events.
+    ///
+    /// Returns nothing for messages that are not market data, and one event per market
+    /// for a resync covering several markets.
+    pub fn from_response(res: KalshiWebsocketResponse) -> Vec<MarketDataEvent> {
+        let event = match res {
+            KalshiWebsocketResponse::Ticker { sid, msg } => MarketDataEvent {
+                sid,
+                ticker: msg.market_ticker.clone(),
+                timestamp: DateTime::from_timestamp(msg.ts, 0),
+                kind: MarketDataKind::Ticker(msg),
+            },
+            KalshiWebsocketResponse::Trade { sid, msg } => MarketDataEvent {
+                sid,
+                ticker: msg.market_ticker.clone(),
+                timestamp: DateTime::from_timestamp(msg.ts, 0),
+                kind: MarketDataKind::Trade(msg),
+            },
+            KalshiWebsocketResponse::OrderbookSnapshot { sid, seq, msg } => MarketDataEvent {
+                sid,
+                ticker: msg.market_ticker.clone(),
+                timestamp: None,
+                kind: MarketDataKind::BookSnapshot { seq, msg },
+            },
+            KalshiWebsocketResponse::OrderbookDelta { sid, seq, msg } => MarketDataEvent {
+                sid,
+                ticker: msg.market_ticker.clone(),
+                timestamp: msg
+                    .ts
+                    .as_deref()
+                    .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+                    .map(|ts| ts.with_timezone(&Utc)),
+                kind: MarketDataKind::BookDelta { seq, msg },
+            },
+            KalshiWebsocketResponse::Resynced {
+                sid,
+                market_tickers,
+            } => {
+                return market_tickers
+                    .into_iter()
+                    .map(|ticker| MarketDataEvent {
+                        sid,
+                        ticker,
+                        timestamp: None,
+                        kind: MarketDataKind::BookResynced,
+                    })
+                    .collect()
+            }
+            _ => return Vec::new(),
+        };
+        vec![event]
+    }
+}
+
+/// A stream carrying a single kind of websocket message.
+///
+/// Connection errors are delivered on every channel stream, so each consumer learns
+/// when the connection is gone.
+pub struct ChannelStream<T> {
+    inner: BoxStream<'static, Result<T, KalshiError>>,
+}
+
+impl<T: Send + 'static> ChannelStream<T> {
+    fn new(
+        messages: KalshiWebsocketStream,
+        select: fn(KalshiWebsocketResponse) -> Option<T>,
+    ) -> Self {
+        let inner = messages.filter_map(move |res| {
+            future::ready(match res {
+                Ok(res) => select(res).map(Ok),
+                Err(e) => Some(Err(e)),
+            })
+        });
+        ChannelStream {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+impl MarketDataStream {
+    fn market_data(messages: KalshiWebsocketStream) -> Self {
+        let inner = messages.flat_map(|res| {
+            let events = match res {
+                Ok(res) => MarketDataEvent::from_response(res)
+                    .into_iter()
+                    .map(Ok)
+                    .collect(),
+                Err(e) => vec![Err(e)],
+            };
+            stream::iter(events)
+        });
+        ChannelStream {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+impl<T> Stream for ChannelStream<T> {
+    type Item = Result<T, KalshiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// Ticker, trade and orderbook messages as a single stream, see
+/// [`KalshiWebsocketClient::market_data`].
+pub type MarketDataStream = ChannelStream<MarketDataEvent>;
+
+pub type TickerStream = ChannelStream<ChannelMessage<KalshiTickerMessage>>;
+pub type TradeStream = ChannelStream<ChannelMessage<KalshiTradeMessage>>;
+pub type FillStream = ChannelStream<ChannelMessage<KalshiFillMessage>>;
+pub type OrderbookStream = ChannelStream<OrderbookUpdate>;
+
+/// Typed streams returned by [`KalshiWebsocketClient::split_channels`].
+pub struct SplitChannels {
+    pub tickers: TickerStream,
+    pub trades: TradeStream,
+    pub fills: FillStream,
+    pub orderbooks: OrderbookStream,
+    /// Every other message, including subscription acknowledgements and errors.
+    pub other: ChannelStream<KalshiWebsocketResponse>,
+}
+
+impl KalshiWebsocketClient {
+    /// Returns ticker, trade and orderbook messages normalized into [`MarketDataEvent`]s.
+    ///
+    /// Lets strategy code handle every market data channel with a single event model.
+    /// Messages are only delivered for channels that have been subscribed to.
+    pub fn market_data(&self) -> MarketDataStream {
+        MarketDataStream::market_data(self.stream())
+    }
+
+    /// Splits incoming messages into one typed stream per channel.
+    ///
+    /// Each stream is independent and can be moved to its own task. Messages are only
+    /// delivered for channels that have been subscribed to.
+    pub fn split_channels(&self) -> SplitChannels {
+        SplitChannels {
+            tickers: ChannelStream::new(self.stream(), |res| match res {
+                KalshiWebsocketResponse::Ticker { sid, msg } => Some(ChannelMessage { sid, msg }),
+                _ => None,
+            }),
+            trades: ChannelStream::new(self.stream(), |res| match res {
+                KalshiWebsocketResponse::Trade { sid, msg } => Some(ChannelMessage { sid, msg }),
+                _ => None,
+            }),
+            fills: ChannelStream::new(self.stream(), |res| match res {
+                KalshiWebsocketResponse::Fill { sid, msg } => Some(ChannelMessage { sid, msg }),
+                _ => None,
+            }),
+            orderbooks: ChannelStream::new(self.stream(), |res| match res {
+                KalshiWebsocketResponse::OrderbookSnapshot { sid, seq, msg } => {
+                    Some(OrderbookUpdate::Snapshot { sid, seq, msg })
+                }
+                KalshiWebsocketResponse::OrderbookDelta { sid, seq, msg } => {
+                    Some(OrderbookUpdate::Delta { sid, seq, msg })
+                }
+                KalshiWebsocketResponse::Resynced {
+                    sid,
+                    market_tickers,
+                } => Some(OrderbookUpdate::Resynced {
+                    sid,
+                    market_tickers,
+                }),
+                _ => None,
+            }),
+            other: ChannelStream::new(self.stream(), |res| match res {
+                KalshiWebsocketResponse::Ticker { .. }
+                | KalshiWebsocketResponse::Trade { .. }
+                | KalshiWebsocketResponse::Fill { .. }
+                | KalshiWebsocketResponse::OrderbookSnapshot { .. }
+                | KalshiWebsocketResponse::OrderbookDelta { .. }
+                | KalshiWebsocketResponse::Resynced { .. } => None,
+                res => Some(res),
+            }),
+        }
+    }
+}
@@ -1,45 +1,89 @@
 #![allow(unused)]
 
-use futures_util::{select_biased, FutureExt, SinkExt, Stream, StreamExt};
+use futures_util::{
+    select_biased,
+    stream::{self, BoxStream},
+    FutureExt, SinkExt, Stream, StreamExt,
+};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use std::{error::Error, str::FromStr, time::Duration, vec};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    error::Error,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, RwLock,
+    },
+    task::{Context, Poll},
+    time::Duration,
+    vec,
+};
 use tokio::{
-    net::TcpStream,
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{lookup_host, TcpSocket, TcpStream},
     sync::{
-        broadcast::{channel, Receiver, Sender},
+        broadcast::{channel, error::RecvError, Receiver, Sender},
         mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+        watch,
     },
     task::JoinHandle,
-    time::{interval, MissedTickBehavior},
+    time::{interval, sleep, timeout, Instant, MissedTickBehavior},
 };
 use tokio_tungstenite::{
-    connect_async,
+    client_async,
     tungstenite::{
         client::IntoClientRequest,
         handshake,
-        http::{HeaderMap, HeaderValue, Request, Uri},
+        http::{HeaderMap, HeaderName, HeaderValue, Request, Uri},
         Message,
     },
     MaybeTlsStream, WebSocketStream,
 };
 
-use crate::{utils::api_key_headers, Kalshi, KalshiAuth};
+use chrono::{DateTime, Utc};
+
+use crate::{
+    redact::redact_headers, retry::backoff_delay, utils::api_key_headers, Kalshi, KalshiAuth,
+    KalshiError, MultivariateLookup, MultivariateMarketLookupResponse, ProxyConfig, Reloadable,
+    Validate,
+};
 
 use super::{
+    builder::KalshiWsClientBuilder,
+    callbacks::Callbacks,
     commands::{
         KalshiCommand, KalshiSubscribeCommandParams, KalshiUnsubscribeCommandParams,
         KalshiUpdateSubscriptionAction, KalshiUpdateSubscriptionCommandParams,
     },
-    responses::KalshiWebsocketResponse,
+    instrumentation,
+    responses::{KalshiErrorMessage, KalshiOkPayload, KalshiWebsocketResponse, ReconnectEvent},
     KalshiChannel,
 };
 
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Most markets sent in a single subscribe command; longer lists are split into batches.
+pub const MAX_MARKETS_PER_COMMAND: usize = 500;
+
+/// Messages buffered per receiver before slow receivers start lagging.
+pub(super) const DEFAULT_BUFFER_SIZE: usize = 1024;
+
 #[derive(Clone, Debug)]
 pub enum KalshiWebsocketError {
     WebSocketError(String),
     SerializationError(String),
     ConnectionClosed,
+    /// The connection could not be authenticated: signing the request failed or Kalshi
+    /// refused the credentials during the handshake.
+    AuthError(String),
+    /// Kalshi rejected a command with an `error` response.
+    CommandRejected(KalshiErrorMessage),
+    /// A receiver fell behind and this many messages were dropped for it.
+    Lagged(u64),
 }
 
 impl std::fmt::Display for KalshiWebsocketError {
@@ -50,17 +94,415 @@ impl std::fmt::Display for KalshiWebsocketError {
                 write!(f, "Serialization error: {}", msg)
             }
             KalshiWebsocketError::ConnectionClosed => write!(f, "Connection closed"),
+            KalshiWebsocketError::AuthError(msg) => write!(f, "Authentication error: {}", msg),
+            KalshiWebsocketError::CommandRejected(e) => {
+                write!(f, "Command rejected: {} (code {})", e.msg, e.code)
+            }
+            KalshiWebsocketError::Lagged(n) => {
+                write!(f, "Receiver lagged behind, {} messages were dropped", n)
+            }
         }
     }
 }
 
 impl std::error::Error for KalshiWebsocketError {}
 
+/// A client for the Kalshi websocket API.
+///
+/// Messages can be consumed by polling the client itself as a [`Stream`], by taking
+/// additional independent streams with [`stream`](KalshiWebsocketClient::stream), or
+/// through a raw broadcast [`receiver`](KalshiWebsocketClient::receiver).
+///
+/// # Sequencing
+///
+/// Messages are delivered in the order Kalshi sent them. The `orderbook_delta` and
+/// `order_group_updates` channels also number their messages per subscription: the
+/// client checks that each `seq` follows the previous one, reports any skip or repeat
+/// as a [`SequenceAnomaly`](KalshiWebsocketResponse::SequenceAnomaly) and exposes the
+/// last number seen through [`last_seq`](KalshiWebsocketClient::last_seq). Numbering
+/// restarts with the snapshot that follows a reconnect or resync. Other channels carry no
+/// sequence numbers, so their losses cannot be detected.
+///
+/// With the `metrics` feature enabled, the client records message counts per type,
+/// the time of the last ticker, deserialization failures, receiver lag, orderbook
+/// resyncs and reconnects through the `metrics` facade, all prefixed with `kalshi_ws_`.
+///
+/// The client never offers `permessage-deflate` compression: `tungstenite` does not
+/// implement websocket extensions, so compressed frames from the server would be
+/// rejected as protocol errors. Frames are always exchanged uncompressed.
 pub struct KalshiWebsocketClient {
     _ws: JoinHandle<()>,
-    next_cmd_id: u32,
+    next_cmd_id: Arc<AtomicU32>,
     to_kalshi: UnboundedSender<KalshiCommand>,
     from_kalshi: Receiver<Result<KalshiWebsocketResponse, KalshiWebsocketError>>,
+    messages: KalshiWebsocketStream,
+    shutdown: ShutdownHandle,
+    /// REST client used for commands Kalshi only accepts over HTTP.
+    rest: Kalshi,
+    hooks: Arc<FrameHooks>,
+    registry: SubscriptionRegistry,
+    last_seqs: SequenceRegistry,
+    connected: watch::Receiver<bool>,
+    pub(super) callbacks: Arc<Callbacks>,
+}
+
+/// The result of [`KalshiWebsocketClient::subscribe`].
+#[derive(Debug, Clone, Default)]
+pub struct SubscribeOutcome {
+    /// Sid of each subscribed channel, in the order the channels were requested.
+    pub sids: Vec<u32>,
+    /// Markets Kalshi refused, with its error. Every other market was subscribed.
+    pub rejected: Vec<(String, KalshiErrorMessage)>,
+}
+
+/// A live subscription, as listed by [`KalshiWebsocketClient::subscriptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionInfo {
+    pub sid: u32,
+    pub channel: KalshiChannel,
+    /// Markets the subscription covers; empty for channel-wide subscriptions.
+    pub market_tickers: Vec<String>,
+    /// When Kalshi first acknowledged the subscription. Reconnects do not reset it.
+    pub subscribed_at: DateTime<Utc>,
+}
+
+/// The markets one subscription should cover, for
+/// [`follow_markets`](KalshiWebsocketClient::follow_markets).
+///
+/// Wrap it in a [`Reloadable`] (for instance loaded with
+/// [`Reloadable::from_file`]) to change the subscribed markets at runtime.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SubscriptionMarkets {
+    pub market_tickers: Vec<String>,
+}
+
+impl Validate for SubscriptionMarkets {
+    fn validate(&self) -> Result<(), KalshiError> {
+        if self.market_tickers.iter().any(|t| t.trim().is_empty()) {
+            return Err(KalshiError::UserInputError(
+                "market_tickers must not contain empty tickers".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Active subscriptions by client sid, maintained by the handler and read by the client.
+type SubscriptionRegistry = Arc<RwLock<HashMap<u32, SubscriptionInfo>>>;
+
+/// Last sequence number seen, keyed by client sid, for subscriptions whose channel has one.
+type SequenceRegistry = Arc<RwLock<HashMap<u32, u32>>>;
+
+/// Which way a raw frame was travelling when it was passed to a [`RawFrameHook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDirection {
+    Inbound,
+    Outbound,
+}
+
+/// Callback that sees the text of every websocket frame, set with
+/// [`KalshiWebsocketClient::on_raw_message`].
+pub type RawFrameHook = Arc<dyn Fn(FrameDirection, &str) + Send + Sync>;
+
+/// Frame-level hooks shared between the client and its background task.
+#[derive(Default)]
+struct FrameHooks {
+    /// Markets whose messages are decoded. `None` lets every market through.
+    ticker_allowlist: RwLock<Option<HashSet<String>>>,
+    /// Called with every text frame before it is parsed or after it is encoded.
+    raw: RwLock<Option<RawFrameHook>>,
+}
+
+impl FrameHooks {
+    fn inspect(&self, direction: FrameDirection, text: &str) {
+        if let Some(hook) = self.raw.read().unwrap().as_ref() {
+            hook(direction, text);
+        }
+    }
+
+    /// Serializes an outgoing command and passes the frame to the raw hook.
+    fn encode(&self, cmd: &KalshiCommand) -> Result<String, KalshiWebsocketError> {
+        let msg = serde_json::to_string(cmd)
+            .map_err(|e| KalshiWebsocketError::SerializationError(e.to_string()))?;
+        self.inspect(FrameDirection::Outbound, &msg);
+        Ok(msg)
+    }
+}
+
+/// A cloneable handle that shuts down a [`KalshiWebsocketClient`] from anywhere.
+///
+/// Shutting down sends any commands already queued, closes the connection with a close
+/// frame, stops reconnect attempts and ends the background task. Every message stream
+/// then yields a final `ConnectionClosed` error and ends.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    signal: Arc<watch::Sender<bool>>,
+    /// Closed once the background task has exited.
+    done: watch::Receiver<()>,
+}
+
+impl ShutdownHandle {
+    /// Requests a shutdown without waiting for it to finish.
+    pub fn trigger(&self) {
+        self.signal.send_replace(true);
+    }
+
+    /// Requests a shutdown and waits until the background task has exited.
+    pub async fn shutdown(&self) {
+        self.trigger();
+        let mut done = self.done.clone();
+        // The sender is never used, so this resolves once the task drops it.
+        while done.changed().await.is_ok() {}
+    }
+
+    /// Returns `true` once a shutdown has been requested.
+    pub fn is_shutdown(&self) -> bool {
+        *self.signal.borrow()
+    }
+}
+
+/// A stream of messages received from the Kalshi websocket.
+///
+/// Each stream receives every message independently. A stream that falls too far behind
+/// yields a [`KalshiWebsocketError::Lagged`] error for the messages it missed and then
+/// continues. The stream ends once the connection is closed for good.
+pub struct KalshiWebsocketStream {
+    inner: BoxStream<'static, Result<KalshiWebsocketResponse, KalshiError>>,
+}
+
+impl KalshiWebsocketStream {
+    fn new(receiver: Receiver<Result<KalshiWebsocketResponse, KalshiWebsocketError>>) -> Self {
+        let inner = stream::unfold(receiver, |mut receiver| async move {
+            let item = match receiver.recv().await {
+                Ok(res) => res.map_err(KalshiError::from),
+                Err(RecvError::Lagged(n)) => {
+                    instrumentation::receiver_lagged(n);
+                    Err(KalshiWebsocketError::Lagged(n).into())
+                }
+                Err(RecvError::Closed) => return None,
+            };
+            Some((item, receiver))
+        });
+        Self::from_boxed(inner.boxed())
+    }
+
+    pub(super) fn from_boxed(
+        inner: BoxStream<'static, Result<KalshiWebsocketResponse, KalshiError>>,
+    ) -> Self {
+        KalshiWebsocketStream { inner }
+    }
+}
+
+impl Stream for KalshiWebsocketStream {
+    type Item = Result<KalshiWebsocketResponse, KalshiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl Stream for KalshiWebsocketClient {
+    type Item = Result<KalshiWebsocketResponse, KalshiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_next_unpin(cx)
+    }
+}
+
+/// Keepalive settings for websocket connections, set with [`Kalshi::with_ws_keepalive`].
+///
+/// A ping is sent every `ping_interval`. If nothing at all (messages, pongs or pings) has
+/// been received for `timeout`, the connection is treated as dead and dropped, which
+/// triggers a reconnect when enabled. The timeout is checked on every ping, so it should
+/// be a few times larger than the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepaliveConfig {
+    /// How often to send a ping. Defaults to 10 seconds.
+    pub ping_interval: Duration,
+    /// How long the connection may stay silent before it is dropped. Defaults to 30 seconds.
+    pub timeout: Duration,
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        KeepaliveConfig {
+            ping_interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// TLS and socket settings for websocket connections, set with
+/// [`Kalshi::with_ws_connector`].
+///
+/// The defaults match a plain connection: system roots, SNI for the URL host, no
+/// connect timeout, an OS-chosen local address and no proxy.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsConnectorConfig {
+    /// Extra PEM encoded root certificates to trust, e.g. for a corporate TLS proxy.
+    pub root_certificates_pem: Vec<Vec<u8>>,
+    /// Host name used for SNI and certificate verification instead of the URL host.
+    pub tls_server_name: Option<String>,
+    /// Maximum time for the TCP connect, TLS handshake and websocket upgrade together.
+    pub connect_timeout: Option<Duration>,
+    /// Local address to bind the socket to before connecting.
+    pub local_address: Option<IpAddr>,
+    /// Proxy to tunnel the connection through.
+    pub proxy: Option<ProxyConfig>,
+}
+
+/// Stale-feed watchdog settings for websocket subscriptions, set with
+/// [`Kalshi::with_ws_watchdog`].
+///
+/// A subscription that delivers no messages for longer than the timeout of its channel
+/// is reported with a [`Stale`](KalshiWebsocketResponse::Stale) message on the stream,
+/// and resubscribed when `resubscribe` is set. Channels differ widely in cadence, so
+/// quiet ones such as fills are usually left unwatched. The default watches nothing.
+///
+/// # Example
+/// ```
+/// # use kalshi::{client::WatchdogConfig, KalshiChannel};
+/// # use std::time::Duration;
+/// let watchdog = WatchdogConfig::default()
+///     .channel_timeout(KalshiChannel::Ticker, Duration::from_secs(60))
+///     .channel_timeout(KalshiChannel::OrderbookDelta, Duration::from_secs(300))
+///     .resubscribe(true);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatchdogConfig {
+    /// Timeout for channels without an entry in `channel_timeouts`. `None` leaves them
+    /// unwatched.
+    pub default_timeout: Option<Duration>,
+    /// Timeouts for individual channels.
+    pub channel_timeouts: HashMap<KalshiChannel, Duration>,
+    /// Whether stale subscriptions are resubscribed automatically.
+    pub resubscribe: bool,
+}
+
+impl WatchdogConfig {
+    /// Sets the timeout for one channel.
+    pub fn channel_timeout(mut self, channel: KalshiChannel, timeout: Duration) -> Self {
+        self.channel_timeouts.insert(channel, timeout);
+        self
+    }
+
+    /// Sets the timeout for every channel without its own.
+    pub fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.default_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether stale subscriptions are resubscribed automatically.
+    pub fn resubscribe(mut self, resubscribe: bool) -> Self {
+        self.resubscribe = resubscribe;
+        self
+    }
+
+    fn timeout_for(&self, channel: &KalshiChannel) -> Option<Duration> {
+        self.channel_timeouts
+            .get(channel)
+            .copied()
+            .or(self.default_timeout)
+    }
+}
+
+/// How often subscriptions are checked against their watchdog timeouts.
+const WATCHDOG_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Most messages held back while waiting for subscribe acknowledgements.
+const MAX_HELD_MESSAGES: usize = 1024;
+
+/// Callback run when a [`ReconnectPolicy`] gives up, with the number of failed attempts.
+pub type GiveUpHook = Arc<dyn Fn(u32) + Send + Sync>;
+
+/// How the client reconnects after the connection drops.
+///
+/// Attempt `n` of an outage waits `initial_backoff * 2^(n-1)`, capped at `max_backoff`.
+/// With `jitter` set, every delay is shortened by a random share of up to that fraction,
+/// so clients dropped together do not all reconnect at the same moment.
+///
+/// Every step is reported on the stream as a
+/// [`Reconnect`](KalshiWebsocketResponse::Reconnect) message.
+///
+/// # Example
+/// ```
+/// # use kalshi::client::ReconnectPolicy;
+/// # use std::time::Duration;
+/// let policy = ReconnectPolicy::new(Duration::from_secs(1), Duration::from_secs(30))
+///     .max_attempts(10)
+///     .jitter(0.2)
+///     .on_give_up(|attempts| eprintln!("websocket gone after {} attempts", attempts));
+/// ```
+#[derive(Clone)]
+pub struct ReconnectPolicy {
+    /// Delay before the first attempt. Defaults to 1 second.
+    pub initial_backoff: Duration,
+    /// Longest delay between attempts. Defaults to 60 seconds.
+    pub max_backoff: Duration,
+    /// Attempts per outage before giving up and closing the client. `None`, the default,
+    /// retries forever.
+    pub max_attempts: Option<u32>,
+    /// Largest share of a delay, between 0 and 1, removed at random. Defaults to 0.
+    pub jitter: f64,
+    /// Called once when the policy gives up.
+    pub on_give_up: Option<GiveUpHook>,
+}
+
+impl ReconnectPolicy {
+    /// Creates a policy that retries forever with exponential backoff and no jitter.
+    pub fn new(initial_backoff: Duration, max_backoff: Duration) -> Self {
+        ReconnectPolicy {
+            initial_backoff,
+            max_backoff: max_backoff.max(initial_backoff),
+            max_attempts: None,
+            jitter: 0.0,
+            on_give_up: None,
+        }
+    }
+
+    /// Gives up after `max_attempts` failed attempts in a row.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Sets the largest share of each delay removed at random, clamped to `0..=1`.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the callback run when the policy gives up.
+    pub fn on_give_up<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u32) + Send + Sync + 'static,
+    {
+        self.on_give_up = Some(Arc::new(hook));
+        self
+    }
+
+    /// Returns the delay before attempt `attempt`, counting from 1.
+    fn delay(&self, attempt: u32) -> Duration {
+        backoff_delay(self.initial_backoff, self.max_backoff, self.jitter, attempt)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy::new(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+impl std::fmt::Debug for ReconnectPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectPolicy")
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("max_attempts", &self.max_attempts)
+            .field("jitter", &self.jitter)
+            .field("on_give_up", &self.on_give_up.is_some())
+            .finish()
+    }
 }
 
 impl Kalshi {
@@ -71,78 +513,290 @@ impl Kalshi {
     pub fn get_ws_url(&self) -> &str {
         &self.ws_url
     }
+
+    /// Overrides the websocket URL derived from the trading environment.
+    ///
+    /// Useful for proxies, staging deployments or any endpoint other than the default
+    /// live and demo URLs. The path of `url` is what gets signed for authentication.
+    pub fn with_ws_url(mut self, url: impl Into<String>) -> Self {
+        self.ws_url = url.into();
+        self
+    }
+
+    /// Sets the TLS and socket settings used by websocket connections opened from this
+    /// client.
+    pub fn with_ws_connector(mut self, connector: WsConnectorConfig) -> Self {
+        self.ws_connector = connector;
+        self
+    }
+
+    /// Sets the ping interval and liveness timeout used by websocket connections opened
+    /// from this client.
+    pub fn with_ws_keepalive(mut self, keepalive: KeepaliveConfig) -> Self {
+        self.ws_keepalive = keepalive;
+        self
+    }
+
+    /// Sets the stale-feed watchdog used by websocket connections opened from this client.
+    pub fn with_ws_watchdog(mut self, watchdog: WatchdogConfig) -> Self {
+        self.ws_watchdog = watchdog;
+        self
+    }
 }
 
-impl<'a> KalshiWebsocketClient {
+impl KalshiWebsocketClient {
+    /// Connects to the Kalshi websocket.
+    ///
+    /// If the connection drops, a `ConnectionClosed` error is sent on the receiver and the
+    /// client stops. Use [`connect_with_reconnect`](KalshiWebsocketClient::connect_with_reconnect)
+    /// to keep the connection alive automatically, or [`builder`](KalshiWebsocketClient::builder)
+    /// for every other setting.
     pub async fn connect(kalshi: &mut Kalshi) -> Result<Self, Box<dyn Error>> {
-        let mut req = Uri::from_str(kalshi.get_ws_url())?.into_client_request()?;
-        let mut headers = req.headers_mut();
-        match &mut kalshi.auth {
-            KalshiAuth::ApiKey { key_id, signer, .. } => {
-                let api_key_headers =
-                    api_key_headers(key_id, signer, "/trade-api/ws/v2", Method::GET)?;
-                for (key, val) in api_key_headers {
-                    headers.insert(key, HeaderValue::from_str(val.as_str())?);
-                }
-            }
-        }
-        let req_clone = req.clone();
-        let (ws_stream, res) = connect_async(req).await.inspect_err(|e| if let tokio_tungstenite::tungstenite::Error::Http(res) = e {
-            if let Some(body) = res.body() {
-                if let Ok(error_body) = String::from_utf8(body.to_vec()) {
-                    tracing::error!("Request was {:?}", req_clone);
-                    tracing::error!("Kalshi error response was {}", error_body);
-                }
-            }
-        })?;
+        Self::connect_inner(kalshi, None, DEFAULT_BUFFER_SIZE).await
+    }
+
+    /// Returns a builder that configures every connection setting in one place.
+    pub fn builder() -> KalshiWsClientBuilder {
+        KalshiWsClientBuilder::default()
+    }
+
+    /// Connects to the Kalshi websocket and reconnects whenever the connection drops.
+    ///
+    /// Reconnect attempts start after `initial_backoff` and double after every failed
+    /// attempt, up to `max_backoff`. Once reconnected, every active subscription is
+    /// replayed. The new subscriptions keep the sids the client already handed out, so
+    /// consumers keep receiving messages under the same sids without doing anything.
+    ///
+    /// Use [`builder`](KalshiWebsocketClient::builder) with a [`ReconnectPolicy`] for
+    /// jitter or a limited number of attempts.
+    pub async fn connect_with_reconnect(
+        kalshi: &mut Kalshi,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Result<Self, Box<dyn Error>> {
+        let policy = ReconnectPolicy::new(initial_backoff, max_backoff);
+        Self::connect_inner(kalshi, Some(policy), DEFAULT_BUFFER_SIZE).await
+    }
+
+    pub(super) async fn connect_inner(
+        kalshi: &mut Kalshi,
+        reconnect: Option<ReconnectPolicy>,
+        buffer_size: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let ws_stream = open_stream(kalshi).await?;
 
         let (to_kalshi_tx, to_kalshi_rx) = unbounded_channel::<KalshiCommand>();
         let (from_kalshi_tx, from_kalshi_rx) =
-            channel::<Result<KalshiWebsocketResponse, KalshiWebsocketError>>(1024);
+            channel::<Result<KalshiWebsocketResponse, KalshiWebsocketError>>(buffer_size.max(1));
+        let next_cmd_id = Arc::new(AtomicU32::new(1));
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let (done_tx, done_rx) = watch::channel(());
+        let hooks = Arc::new(FrameHooks::default());
+        let registry = SubscriptionRegistry::default();
+        let last_seqs = SequenceRegistry::default();
+        let state = SubscriptionState::new(
+            Arc::clone(&next_cmd_id),
+            Arc::clone(&registry),
+            Arc::clone(&last_seqs),
+            kalshi.ws_watchdog.clone(),
+        );
 
-        let _ws = tokio::spawn(kalshi_ws_handler(ws_stream, from_kalshi_tx, to_kalshi_rx));
+        let (connected_tx, connected_rx) = watch::channel(true);
+
+        let handler = kalshi_ws_handler(
+            HandlerContext {
+                kalshi: kalshi.clone(),
+                reconnect,
+                state,
+                shutdown: shutdown_rx,
+                connected: connected_tx,
+                hooks: Arc::clone(&hooks),
+                from_kalshi_tx,
+                to_kalshi_rx,
+            },
+            ws_stream,
+        );
+        let _ws = tokio::spawn(async move {
+            let _done = done_tx;
+            handler.await;
+        });
+        let signal = Arc::new(shutdown_tx);
+        if let Some(token) = kalshi.cancellation.clone() {
+            let signal = Arc::clone(&signal);
+            let mut done = done_rx.clone();
+            tokio::spawn(async move {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        signal.send_replace(true);
+                    }
+                    _ = async { while done.changed().await.is_ok() {} } => {}
+                }
+            });
+        }
 
         Ok(KalshiWebsocketClient {
-            next_cmd_id: 1,
+            next_cmd_id,
             to_kalshi: to_kalshi_tx,
+            messages: KalshiWebsocketStream::new(from_kalshi_rx.resubscribe()),
             from_kalshi: from_kalshi_rx,
+            shutdown: ShutdownHandle {
+                signal,
+                done: done_rx,
+            },
+            rest: kalshi.clone(),
+            hooks,
+            registry,
+            last_seqs,
+            connected: connected_rx,
+            callbacks: Arc::default(),
             _ws,
         })
     }
 
+    fn next_id(&self) -> u32 {
+        self.next_cmd_id.fetch_add(1, Ordering::Relaxed)
+    }
+
     /// Subscribe to one or more channels using the provided parameters.
-    /// 
+    ///
+    /// Resolves once Kalshi has acknowledged every channel, with the sid of each
+    /// subscription in the same order as `params.channels`.
+    ///
+    /// Market lists longer than [`MAX_MARKETS_PER_COMMAND`] are split into several
+    /// subscribe commands sent together. Each batch gets its own sid per channel, so the
+    /// outcome then lists the sids of the first batch, then the second, and so on.
+    ///
+    /// If Kalshi rejects one of the requested markets, that market is dropped and the
+    /// remaining ones are subscribed again; the rejected markets are listed in the
+    /// outcome. Any other rejection is returned as [`KalshiWebsocketError::CommandRejected`].
+    ///
     /// If subscribing to `OrderbookDelta`, a market specification (ticker or tickers) is required.
     pub async fn subscribe(
         &mut self,
         params: KalshiSubscribeCommandParams,
-    ) -> Result<u32, Box<dyn Error>> {
-        let cmd_id = self.next_cmd_id;
-        if params.channels.contains(&KalshiChannel::OrderbookDelta) 
-            && params.market_ticker.is_none() 
-            && params.market_tickers.as_ref().map_or(true, |v| v.is_empty()) 
+    ) -> Result<SubscribeOutcome, Box<dyn Error>> {
+        if params.channels.contains(&KalshiChannel::OrderbookDelta)
+            && params.market_ticker.is_none()
+            && params
+                .market_tickers
+                .as_ref()
+                .map_or(true, |v| v.is_empty())
         {
-            return Err("Cannot subscribe to orderbook deltas without providing a market ticker or tickers".to_string().into());
+            return Err(
+                "Cannot subscribe to orderbook deltas without providing a market ticker or tickers"
+                    .to_string()
+                    .into(),
+            );
         }
-        let msg = KalshiCommand::Subscribe {
-            id: cmd_id,
-            params,
-        };
-        self.to_kalshi.send(msg)?;
-        self.next_cmd_id += 1;
-        Ok(cmd_id)
+        let tickers = params.tickers();
+        if tickers.len() <= MAX_MARKETS_PER_COMMAND {
+            return Ok(self.subscribe_batch(params).await?);
+        }
+        let batches = tickers.chunks(MAX_MARKETS_PER_COMMAND).map(|chunk| {
+            self.subscribe_batch(KalshiSubscribeCommandParams {
+                market_ticker: None,
+                market_tickers: Some(chunk.to_vec()),
+                ..params.clone()
+            })
+        });
+        let mut outcome = SubscribeOutcome::default();
+        for batch in futures_util::future::try_join_all(batches).await? {
+            outcome.sids.extend(batch.sids);
+            outcome.rejected.extend(batch.rejected);
+        }
+        Ok(outcome)
     }
 
-    /// Unsubscribe one or more existing subscriptions
-    pub async fn unsubscribe(&mut self, sids: Vec<u32>) -> Result<u32, Box<dyn Error>> {
-        let cmd_id = self.next_cmd_id;
+    /// Sends one subscribe command and waits for it, retrying without rejected markets.
+    async fn subscribe_batch(
+        &self,
+        mut params: KalshiSubscribeCommandParams,
+    ) -> Result<SubscribeOutcome, KalshiWebsocketError> {
+        let channels = params.channels.clone();
+        let mut sids = HashMap::new();
+        let mut rejected = Vec::new();
+        loop {
+            // Channels acknowledged by an earlier attempt stay subscribed.
+            params.channels.retain(|c| !sids.contains_key(c));
+            if params.channels.is_empty() {
+                break;
+            }
+            let pending = params.channels.clone();
+            let responses = self.from_kalshi.resubscribe();
+            let cmd_id = self.next_id();
+            let msg = KalshiCommand::Subscribe {
+                id: cmd_id,
+                params: params.clone(),
+            };
+            self.to_kalshi
+                .send(msg)
+                .map_err(|_| KalshiWebsocketError::ConnectionClosed)?;
+
+            let ack = wait_for_ack(responses, cmd_id, |res| {
+                if let KalshiWebsocketResponse::Subscribed { id: Some(id), msg } = res {
+                    if id == cmd_id {
+                        sids.insert(msg.channel, msg.sid);
+                    }
+                }
+                pending.iter().all(|c| sids.contains_key(c))
+            })
+            .await;
+            match ack {
+                Ok(()) => break,
+                Err(KalshiWebsocketError::CommandRejected(err)) => {
+                    let tickers = params.tickers();
+                    let Some(ticker) = err.market_ticker.clone().filter(|t| tickers.contains(t))
+                    else {
+                        return Err(KalshiWebsocketError::CommandRejected(err));
+                    };
+                    tracing::warn!("Kalshi rejected market {}: {}", ticker, err.msg);
+                    params.remove_ticker(&ticker);
+                    rejected.push((ticker, err));
+                    if params.tickers().is_empty() {
+                        break;
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(SubscribeOutcome {
+            sids: channels
+                .iter()
+                .filter_map(|c| sids.get(c).copied())
+                .collect(),
+            rejected,
+        })
+    }
+
+    /// Unsubscribe one or more existing subscriptions.
+    ///
+    /// Resolves once Kalshi has acknowledged every sid, at which point the subscriptions
+    /// are forgotten and will not be restored on reconnect. Returns an error if Kalshi
+    /// rejects the command or the connection closes first.
+    pub async fn unsubscribe(&mut self, sids: Vec<u32>) -> Result<(), Box<dyn Error>> {
+        if sids.is_empty() {
+            return Ok(());
+        }
+        // Subscribe to responses before sending so the acknowledgement cannot be missed.
+        let responses = self.from_kalshi.resubscribe();
+        let mut remaining: HashSet<u32> = sids.iter().copied().collect();
+        let cmd_id = self.next_id();
         let msg = KalshiCommand::Unsubscribe {
             id: cmd_id,
             params: KalshiUnsubscribeCommandParams { sids },
         };
         self.to_kalshi.send(msg)?;
-        self.next_cmd_id += 1;
-        Ok(cmd_id)
+
+        wait_for_ack(responses, cmd_id, |res| {
+            if let KalshiWebsocketResponse::Unsubscribed { id, sid, .. } = res {
+                if id.map_or(true, |id| id == cmd_id) {
+                    remaining.remove(&sid);
+                }
+            }
+            remaining.is_empty()
+        })
+        .await?;
+        Ok(())
     }
 
     /// Add or delete markets on an existing subscription using the provided parameters.
@@ -150,94 +804,1555 @@ impl<'a> KalshiWebsocketClient {
         &mut self,
         params: KalshiUpdateSubscriptionCommandParams,
     ) -> Result<u32, Box<dyn Error>> {
-        let cmd_id = self.next_cmd_id;
-        let msg = KalshiCommand::UpdateSubscription {
-            id: cmd_id,
-            params,
-        };
+        let cmd_id = self.next_id();
+        let msg = KalshiCommand::UpdateSubscription { id: cmd_id, params };
         self.to_kalshi.send(msg)?;
-        self.next_cmd_id += 1;
         Ok(cmd_id)
     }
 
+    /// Adds markets to an existing subscription without recreating it.
+    ///
+    /// Returns the command id, which the `Ok` acknowledgement echoes back.
+    pub async fn add_markets(
+        &mut self,
+        sid: u32,
+        tickers: Vec<String>,
+    ) -> Result<u32, Box<dyn Error>> {
+        self.update_subscription(KalshiUpdateSubscriptionCommandParams {
+            action: KalshiUpdateSubscriptionAction::AddMarkets,
+            sid: Some(sid),
+            market_tickers: Some(tickers),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Removes markets from an existing subscription without recreating it.
+    ///
+    /// Returns the command id, which the `Ok` acknowledgement echoes back.
+    pub async fn remove_markets(
+        &mut self,
+        sid: u32,
+        tickers: Vec<String>,
+    ) -> Result<u32, Box<dyn Error>> {
+        self.update_subscription(KalshiUpdateSubscriptionCommandParams {
+            action: KalshiUpdateSubscriptionAction::DeleteMarkets,
+            sid: Some(sid),
+            market_tickers: Some(tickers),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Keeps the markets of subscription `sid` in line with `markets`.
+    ///
+    /// Spawns a task that adds and removes markets with update commands, first to match
+    /// the current set and then after every reload, so the subscription follows the
+    /// configuration without being recreated. The task ends when the subscription is
+    /// closed or the client shuts down.
+    pub fn follow_markets(
+        &self,
+        sid: u32,
+        markets: &Reloadable<SubscriptionMarkets>,
+    ) -> JoinHandle<()> {
+        let mut changes = markets.subscribe();
+        let registry = Arc::clone(&self.registry);
+        let to_kalshi = self.to_kalshi.clone();
+        let next_cmd_id = Arc::clone(&self.next_cmd_id);
+        tokio::spawn(async move {
+            loop {
+                let wanted: HashSet<String> = changes
+                    .borrow_and_update()
+                    .market_tickers
+                    .iter()
+                    .cloned()
+                    .collect();
+                let Some(current) = registry.read().unwrap().get(&sid).map(|sub| {
+                    sub.market_tickers
+                        .iter()
+                        .cloned()
+                        .collect::<HashSet<String>>()
+                }) else {
+                    return;
+                };
+                let mut added: Vec<String> = wanted.difference(&current).cloned().collect();
+                let mut removed: Vec<String> = current.difference(&wanted).cloned().collect();
+                added.sort();
+                removed.sort();
+                for (action, tickers) in [
+                    (KalshiUpdateSubscriptionAction::AddMarkets, added),
+                    (KalshiUpdateSubscriptionAction::DeleteMarkets, removed),
+                ] {
+                    if tickers.is_empty() {
+                        continue;
+                    }
+                    let msg = KalshiCommand::UpdateSubscription {
+                        id: next_cmd_id.fetch_add(1, Ordering::Relaxed),
+                        params: KalshiUpdateSubscriptionCommandParams {
+                            action,
+                            sid: Some(sid),
+                            market_tickers: Some(tickers),
+                            ..Default::default()
+                        },
+                    };
+                    if to_kalshi.send(msg).is_err() {
+                        return;
+                    }
+                }
+                if changes.changed().await.is_err() {
+                    return;
+                }
+            }
+        })
+    }
+
+    /// Submits a multivariate lookup for a combination of selected markets over REST.
+    ///
+    /// The websocket API has no lookup command, so this calls
+    /// [`Kalshi::lookup_multivariate`] with the client's REST connection. The resulting
+    /// `MultivariateLookup` notification arrives on this client if it is subscribed to
+    /// the `multivariate` channel.
+    pub async fn lookup_multivariate_via_rest(
+        &self,
+        lookup: &MultivariateLookup,
+    ) -> Result<MultivariateMarketLookupResponse, KalshiError> {
+        self.rest.lookup_multivariate(lookup).await
+    }
+
+    /// Forces a fresh orderbook snapshot for `market_ticker`.
+    ///
+    /// The orderbook subscription covering the market is resubscribed under the same
+    /// sid, which also refreshes every other market it covers. Deltas are dropped until
+    /// the new snapshot arrives, which is announced with a
+    /// [`Resynced`](KalshiWebsocketResponse::Resynced) message. Useful when the
+    /// application suspects its local book, e.g. after a long pause.
+    ///
+    /// Returns the sid of the subscription, or an error if no orderbook subscription
+    /// covers the market.
+    pub fn refresh_book(&self, market_ticker: &str) -> Result<u32, Box<dyn Error>> {
+        let sid = self
+            .subscriptions()
+            .into_iter()
+            .find(|sub| {
+                sub.channel == KalshiChannel::OrderbookDelta
+                    && sub.market_tickers.iter().any(|t| t == market_ticker)
+            })
+            .map(|sub| sub.sid)
+            .ok_or_else(|| format!("No orderbook subscription covers {}", market_ticker))?;
+        self.to_kalshi.send(KalshiCommand::Resync { sid })?;
+        Ok(sid)
+    }
+
     /// List all active subscriptions.
     pub async fn list_subscriptions(&mut self) -> Result<u32, Box<dyn Error>> {
-        let cmd_id = self.next_cmd_id;
+        let cmd_id = self.next_id();
         let msg = KalshiCommand::ListSubscriptions { id: cmd_id };
         self.to_kalshi.send(msg)?;
-        self.next_cmd_id += 1;
         Ok(cmd_id)
     }
 
-    /// Get a broadcast receiver from the websocket stream
+    /// Get a broadcast receiver from the websocket stream.
+    ///
+    /// Every receiver sees every decoded message, so any number of tasks can consume the
+    /// same subscriptions over a single connection. For the latest ticker per market
+    /// only, see [`LatestTickers`](super::latest::LatestTickers).
     pub fn receiver(&self) -> Receiver<Result<KalshiWebsocketResponse, KalshiWebsocketError>> {
         self.from_kalshi.resubscribe()
     }
 
-    /// Gracefully closes the websocket connection consuming the client
-    fn close(self) -> Result<(), Box<dyn Error>> {
-        self.to_kalshi.send(KalshiCommand::End)?;
-        Ok(())
+    /// Returns a new, independent stream of websocket messages.
+    ///
+    /// The stream starts with the next message received and does not borrow the client,
+    /// so it can be moved into another task.
+    pub fn stream(&self) -> KalshiWebsocketStream {
+        KalshiWebsocketStream::new(self.from_kalshi.resubscribe())
+    }
+
+    /// Only decodes and delivers market data for the given market tickers.
+    ///
+    /// Frames for other markets are recognised from their raw text and dropped without
+    /// being deserialized, which saves CPU when a channel-wide subscription such as
+    /// `ticker` or `trade` covers far more markets than the application needs.
+    /// Orderbook messages and command responses are never filtered, so orderbook
+    /// sequencing stays intact.
+    pub fn set_ticker_allowlist<I, S>(&self, tickers: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        *self.hooks.ticker_allowlist.write().unwrap() =
+            Some(tickers.into_iter().map(Into::into).collect());
+    }
+
+    /// Removes the ticker allowlist so messages for every market are delivered again.
+    pub fn clear_ticker_allowlist(&self) {
+        *self.hooks.ticker_allowlist.write().unwrap() = None;
+    }
+
+    /// Calls `hook` with the text of every frame sent or received, before any parsing.
+    ///
+    /// Useful for audit capture, custom logging, or seeing exactly which frame failed to
+    /// deserialize. The hook runs on the connection task, so it should return quickly.
+    /// Replaces any previously set hook.
+    pub fn on_raw_message<F>(&self, hook: F)
+    where
+        F: Fn(FrameDirection, &str) + Send + Sync + 'static,
+    {
+        *self.hooks.raw.write().unwrap() = Some(Arc::new(hook));
+    }
+
+    /// Removes the hook set with [`on_raw_message`](KalshiWebsocketClient::on_raw_message).
+    pub fn clear_raw_message_hook(&self) {
+        *self.hooks.raw.write().unwrap() = None;
+    }
+
+    /// Returns every active subscription, ordered by sid.
+    pub fn subscriptions(&self) -> Vec<SubscriptionInfo> {
+        let mut subscriptions: Vec<SubscriptionInfo> =
+            self.registry.read().unwrap().values().cloned().collect();
+        subscriptions.sort_by_key(|sub| sub.sid);
+        subscriptions
+    }
+
+    /// Returns the active subscription with the given sid.
+    pub fn subscription(&self, sid: u32) -> Option<SubscriptionInfo> {
+        self.registry.read().unwrap().get(&sid).cloned()
+    }
+
+    /// Returns the subscription that produced a message, if it carries a sid.
+    pub fn subscription_for(&self, message: &KalshiWebsocketResponse) -> Option<SubscriptionInfo> {
+        self.subscription(message.sid()?)
+    }
+
+    /// Returns the shared subscription registry, for helpers that outlive a borrow of
+    /// the client.
+    pub(super) fn registry(&self) -> SubscriptionRegistry {
+        Arc::clone(&self.registry)
+    }
+
+    /// Returns the last sequence number received on a subscription.
+    ///
+    /// Only `orderbook_delta` and `order_group_updates` subscriptions are numbered; every
+    /// other sid returns `None`, as does a subscription that has not delivered a message.
+    pub fn last_seq(&self, sid: u32) -> Option<u32> {
+        self.last_seqs.read().unwrap().get(&sid).copied()
+    }
+
+    /// Returns whether the websocket is currently connected.
+    ///
+    /// Turns `false` while a dropped connection is being reestablished, and for good once
+    /// the client has shut down.
+    pub fn is_connected(&self) -> bool {
+        *self.connected.borrow()
+    }
+
+    /// Returns a receiver that changes whenever the websocket connects or disconnects.
+    pub fn connection_status(&self) -> watch::Receiver<bool> {
+        self.connected.clone()
+    }
+
+    /// Returns `true` once the connection is gone for good and the background task has
+    /// exited.
+    pub fn is_closed(&self) -> bool {
+        self._ws.is_finished()
+    }
+
+    /// Returns a handle that can shut the client down from another task.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Gracefully closes the websocket connection consuming the client.
+    ///
+    /// Resolves once queued commands are sent and the background task has exited.
+    pub async fn close(self) {
+        self.shutdown.shutdown().await;
+    }
+}
+
+/// Signs a websocket upgrade request with fresh auth headers and opens the connection.
+async fn open_stream(kalshi: &mut Kalshi) -> Result<WsStream, KalshiWebsocketError> {
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    let uri = Uri::from_str(kalshi.get_ws_url()).map_err(|e| ws_error(&e))?;
+    let path = uri.path().to_string();
+    let mut req = uri.into_client_request().map_err(|e| ws_error(&e))?;
+    let auth_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::AuthError(e.to_string());
+    // Headers are signed with a fresh timestamp on every attempt, including reconnects.
+    let headers = req.headers_mut();
+    for (name, value) in &kalshi.default_headers {
+        if !headers.contains_key(name.as_str()) {
+            headers.insert(
+                HeaderName::from_bytes(name.as_str().as_bytes()).map_err(|e| ws_error(&e))?,
+                HeaderValue::from_bytes(value.as_bytes()).map_err(|e| ws_error(&e))?,
+            );
+        }
+    }
+    match &*kalshi.auth() {
+        KalshiAuth::ApiKey { key_id, signer, .. } => {
+            let api_key_headers =
+                api_key_headers(key_id, signer, &path, Method::GET, kalshi.clock_offset_ms())
+                    .map_err(|e| auth_error(&e))?;
+            for (key, val) in api_key_headers {
+                headers.insert(
+                    key,
+                    HeaderValue::from_str(val.as_str()).map_err(|e| auth_error(&e))?,
+                );
+            }
+        }
+    }
+    let req_clone = req.clone();
+    let config = &kalshi.ws_connector;
+    let handshake = async {
+        let transport = connect_transport(req.uri(), config).await?;
+        client_async(req, transport).await.map_err(|e| {
+            if let tokio_tungstenite::tungstenite::Error::Http(res) = &e {
+                let body = res
+                    .body()
+                    .as_ref()
+                    .and_then(|body| String::from_utf8(body.to_vec()).ok());
+                if let Some(error_body) = &body {
+                    tracing::error!(
+                        "Request was {} {} [{}]",
+                        req_clone.method(),
+                        req_clone.uri(),
+                        redact_headers(
+                            req_clone
+                                .headers()
+                                .iter()
+                                .map(|(name, value)| (name.as_str(), value.as_bytes()))
+                        )
+                    );
+                    tracing::error!("Kalshi error response was {}", error_body);
+                }
+                if matches!(res.status().as_u16(), 401 | 403) {
+                    return auth_error(&format!(
+                        "Handshake rejected with status {}: {}",
+                        res.status(),
+                        body.unwrap_or_default()
+                    ));
+                }
+            }
+            ws_error(&e)
+        })
+    };
+    let (ws_stream, _) = match config.connect_timeout {
+        Some(limit) => timeout(limit, handshake)
+            .await
+            .map_err(|_| ws_error(&format!("Connection timed out after {:?}", limit)))??,
+        None => handshake.await?,
+    };
+    Ok(ws_stream)
+}
+
+/// Opens the TCP connection for `uri` and wraps it in TLS for `wss` URLs.
+async fn connect_transport(
+    uri: &Uri,
+    config: &WsConnectorConfig,
+) -> Result<MaybeTlsStream<TcpStream>, KalshiWebsocketError> {
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    let host = uri
+        .host()
+        .ok_or_else(|| ws_error(&"Websocket URL has no host"))?;
+    let tls = uri.scheme_str() == Some("wss");
+    let port = uri.port_u16().unwrap_or(if tls { 443 } else { 80 });
+
+    let tcp = match config.proxy.as_ref().filter(|proxy| !proxy.bypasses(host)) {
+        Some(proxy) => connect_proxy(proxy, host, port, config.local_address).await?,
+        None => connect_tcp(host, port, config.local_address).await?,
+    };
+    if !tls {
+        return Ok(MaybeTlsStream::Plain(tcp));
+    }
+
+    let server_name = config.tls_server_name.as_deref().unwrap_or(host);
+    connect_tls(tcp, server_name, config).await
+}
+
+/// Wraps a TCP connection in TLS using rustls.
+#[cfg(feature = "rustls")]
+async fn connect_tls(
+    tcp: TcpStream,
+    server_name: &str,
+    config: &WsConnectorConfig,
+) -> Result<MaybeTlsStream<TcpStream>, KalshiWebsocketError> {
+    use tokio_rustls::rustls::{self, pki_types::ServerName};
+
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    for pem in &config.root_certificates_pem {
+        for cert in rustls_pemfile::certs(&mut pem.as_slice()) {
+            roots
+                .add(cert.map_err(|e| ws_error(&e))?)
+                .map_err(|e| ws_error(&e))?;
+        }
+    }
+    let tls_config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| ws_error(&e))?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    let server_name = ServerName::try_from(server_name.to_string()).map_err(|e| ws_error(&e))?;
+    let stream = tokio_rustls::TlsConnector::from(Arc::new(tls_config))
+        .connect(server_name, tcp)
+        .await
+        .map_err(|e| ws_error(&e))?;
+    Ok(MaybeTlsStream::Rustls(stream))
+}
+
+/// Wraps a TCP connection in TLS using the platform's TLS library.
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+async fn connect_tls(
+    tcp: TcpStream,
+    server_name: &str,
+    config: &WsConnectorConfig,
+) -> Result<MaybeTlsStream<TcpStream>, KalshiWebsocketError> {
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    let mut builder = native_tls::TlsConnector::builder();
+    for pem in &config.root_certificates_pem {
+        let cert = native_tls::Certificate::from_pem(pem).map_err(|e| ws_error(&e))?;
+        builder.add_root_certificate(cert);
+    }
+    let connector =
+        tokio_native_tls::TlsConnector::from(builder.build().map_err(|e| ws_error(&e))?);
+    let stream = connector
+        .connect(server_name, tcp)
+        .await
+        .map_err(|e| ws_error(&e))?;
+    Ok(MaybeTlsStream::NativeTls(stream))
+}
+
+/// Opens a TCP connection, optionally from a specific local address.
+async fn connect_tcp(
+    host: &str,
+    port: u16,
+    local_address: Option<IpAddr>,
+) -> Result<TcpStream, KalshiWebsocketError> {
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    match local_address {
+        Some(local) => {
+            let remote = lookup_host((host, port))
+                .await
+                .map_err(|e| ws_error(&e))?
+                .find(|addr| addr.is_ipv4() == local.is_ipv4())
+                .ok_or_else(|| ws_error(&format!("No address for {} matches {}", host, local)))?;
+            let socket = if local.is_ipv4() {
+                TcpSocket::new_v4()
+            } else {
+                TcpSocket::new_v6()
+            }
+            .map_err(|e| ws_error(&e))?;
+            socket
+                .bind(SocketAddr::new(local, 0))
+                .map_err(|e| ws_error(&e))?;
+            socket.connect(remote).await.map_err(|e| ws_error(&e))
+        }
+        None => TcpStream::connect((host, port))
+            .await
+            .map_err(|e| ws_error(&e)),
     }
 }
 
-async fn kalshi_ws_handler(
-    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+/// Opens a TCP connection to `host` tunnelled through a proxy.
+async fn connect_proxy(
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+    local_address: Option<IpAddr>,
+) -> Result<TcpStream, KalshiWebsocketError> {
+    let ws_error = |e: &dyn std::fmt::Display| KalshiWebsocketError::WebSocketError(e.to_string());
+    let url = proxy.parsed_url().map_err(|e| ws_error(&e))?;
+    let credentials = proxy.credentials().map_err(|e| ws_error(&e))?;
+    let proxy_host = url
+        .host_str()
+        .ok_or_else(|| ws_error(&format!("Proxy URL {} has no host", proxy.url)))?;
+    let default_port = if url.scheme() == "http" { 80 } else { 1080 };
+    let proxy_port = url.port().unwrap_or(default_port);
+    let mut tcp = connect_tcp(proxy_host, proxy_port, local_address).await?;
+
+    match url.scheme() {
+        "http" => {
+            let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+            if let Some((username, password)) = credentials {
+                use base64::Engine;
+                let token = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{}:{}", username, password));
+                request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+            }
+            request.push_str("\r\n");
+            tcp.write_all(request.as_bytes())
+                .await
+                .map_err(|e| ws_error(&e))?;
+
+            // Read the response head byte by byte so no tunnelled bytes are consumed.
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                if head.len() > 8192 {
+                    return Err(ws_error(&"Proxy response header is too long"));
+                }
+                head.push(tcp.read_u8().await.map_err(|e| ws_error(&e))?);
+            }
+            let head = String::from_utf8_lossy(&head);
+            let status_line = head.lines().next().unwrap_or_default();
+            match status_line.split_whitespace().nth(1) {
+                Some(status) if status.starts_with('2') => Ok(tcp),
+                _ => Err(ws_error(&format!("Proxy refused CONNECT: {}", status_line))),
+            }
+        }
+        #[cfg(feature = "socks")]
+        "socks5" | "socks5h" => {
+            let stream = match &credentials {
+                Some((username, password)) => {
+                    tokio_socks::tcp::Socks5Stream::connect_with_password_and_socket(
+                        tcp,
+                        (host, port),
+                        username,
+                        password,
+                    )
+                    .await
+                }
+                None => {
+                    tokio_socks::tcp::Socks5Stream::connect_with_socket(tcp, (host, port)).await
+                }
+            };
+            Ok(stream.map_err(|e| ws_error(&e))?.into_inner())
+        }
+        #[cfg(not(feature = "socks"))]
+        "socks5" | "socks5h" => Err(ws_error(&"SOCKS proxies need the `socks` feature")),
+        scheme => Err(ws_error(&format!("Unsupported proxy scheme {}", scheme))),
+    }
+}
+
+/// A subscription as the client sees it, independent of the connection it currently lives on.
+struct ActiveSubscription {
+    /// Parameters that recreate this subscription, narrowed to its single channel.
+    params: KalshiSubscribeCommandParams,
+    /// The sid the server assigned on the current connection.
+    server_sid: u32,
+}
+
+/// Subscription bookkeeping that survives reconnects.
+///
+/// The first time a subscription is acknowledged, the server's sid is handed to the
+/// client as-is (unless it is already taken). After a reconnect the server assigns new
+/// sids, and every message is translated back to the sid the client already knows.
+///
+/// Orderbook subscriptions are also checked for sequence gaps. On a gap the subscription
+/// is resubscribed under the same client sid to force a fresh snapshot. Subscriptions
+/// that stay silent past their watchdog timeout are reported, and optionally
+/// resubscribed the same way.
+struct SubscriptionState {
+    next_cmd_id: Arc<AtomicU32>,
+    /// Public view of `active`, shared with the client.
+    registry: SubscriptionRegistry,
+    /// Public view of the last sequence numbers, shared with the client.
+    last_seqs: SequenceRegistry,
+    /// Active subscriptions keyed by client-facing sid.
+    active: HashMap<u32, ActiveSubscription>,
+    /// Server sid on the current connection to client-facing sid.
+    server_to_client: HashMap<u32, u32>,
+    /// Subscribe commands sent by the client that are not fully acknowledged yet.
+    pending: HashMap<u32, KalshiSubscribeCommandParams>,
+    /// Internal resubscribe commands, keyed by command id, for the client sid they restore.
+    replays: HashMap<u32, u32>,
+    /// Update commands that are not acknowledged yet, keyed by command id, with the
+    /// client sid they change.
+    updates: HashMap<u32, (u32, KalshiUpdateSubscriptionCommandParams)>,
+    /// Internal unsubscribe commands sent to force an orderbook resync.
+    resync_unsubscribes: HashSet<u32>,
+    /// Last orderbook sequence number seen, keyed by client sid.
+    sequences: HashMap<u32, u32>,
+    /// Orderbook subscriptions waiting for a fresh snapshot after a gap.
+    resyncing: HashSet<u32>,
+    /// Internal commands to send before handling the next message.
+    outgoing: Vec<KalshiCommand>,
+    /// Client-generated notifications to emit ahead of the current message.
+    notices: Vec<KalshiWebsocketResponse>,
+    watchdog: WatchdogConfig,
+    /// When each subscription last delivered a message, keyed by client sid.
+    last_message: HashMap<u32, Instant>,
+    /// Subscriptions already reported stale and not resubscribed.
+    stale: HashSet<u32>,
+    /// Messages for server sids that are not mapped yet, held until the subscribe
+    /// acknowledgement that maps them arrives.
+    held: HashMap<u32, Vec<KalshiWebsocketResponse>>,
+}
+
+impl SubscriptionState {
+    fn new(
+        next_cmd_id: Arc<AtomicU32>,
+        registry: SubscriptionRegistry,
+        last_seqs: SequenceRegistry,
+        watchdog: WatchdogConfig,
+    ) -> Self {
+        SubscriptionState {
+            next_cmd_id,
+            registry,
+            last_seqs,
+            active: HashMap::new(),
+            server_to_client: HashMap::new(),
+            pending: HashMap::new(),
+            replays: HashMap::new(),
+            updates: HashMap::new(),
+            resync_unsubscribes: HashSet::new(),
+            sequences: HashMap::new(),
+            resyncing: HashSet::new(),
+            outgoing: Vec::new(),
+            notices: Vec::new(),
+            watchdog,
+            last_message: HashMap::new(),
+            stale: HashSet::new(),
+            held: HashMap::new(),
+        }
+    }
+
+    fn next_id(&self) -> u32 {
+        self.next_cmd_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Mirrors the current state of one subscription into the public registry.
+    fn publish(&self, client_sid: u32) {
+        let mut registry = self.registry.write().unwrap();
+        let Some(sub) = self.active.get(&client_sid) else {
+            registry.remove(&client_sid);
+            self.last_seqs.write().unwrap().remove(&client_sid);
+            return;
+        };
+        let Some(channel) = sub.params.channels.first().cloned() else {
+            return;
+        };
+        let subscribed_at = registry
+            .get(&client_sid)
+            .map_or_else(Utc::now, |info| info.subscribed_at);
+        registry.insert(
+            client_sid,
+            SubscriptionInfo {
+                sid: client_sid,
+                channel,
+                market_tickers: sub.params.tickers(),
+                subscribed_at,
+            },
+        );
+    }
+
+    fn client_sid(&self, server_sid: u32) -> u32 {
+        self.server_to_client
+            .get(&server_sid)
+            .copied()
+            .unwrap_or(server_sid)
+    }
+
+    fn server_sid(&self, client_sid: u32) -> u32 {
+        self.active
+            .get(&client_sid)
+            .map_or(client_sid, |sub| sub.server_sid)
+    }
+
+    /// Rewrites client sids in an outgoing command to the sids of the current connection.
+    fn prepare_command(&mut self, cmd: &mut KalshiCommand) {
+        match cmd {
+            KalshiCommand::Subscribe { id, params } => {
+                self.pending.insert(*id, params.clone());
+            }
+            KalshiCommand::Unsubscribe { params, .. } => {
+                for sid in params.sids.iter_mut() {
+                    *sid = self.server_sid(*sid);
+                }
+            }
+            KalshiCommand::UpdateSubscription { id, params } => {
+                if let Some(client_sid) = params.sid.or(params.sids.map(|[sid]| sid)) {
+                    self.updates.insert(*id, (client_sid, params.clone()));
+                }
+                if let Some(sid) = params.sid.as_mut() {
+                    *sid = self.server_sid(*sid);
+                }
+                if let Some([sid]) = params.sids.as_mut() {
+                    *sid = self.server_sid(*sid);
+                }
+            }
+            KalshiCommand::ListSubscriptions { .. }
+            | KalshiCommand::End
+            | KalshiCommand::Resync { .. } => {}
+        }
+    }
+
+    /// Returns the messages ready to be processed now that `response` arrived.
+    ///
+    /// Messages for a server sid without a client sid are held while a subscribe is
+    /// waiting for its acknowledgement, since they belong to that subscription, and are
+    /// released right after the acknowledgement. Once no subscribe is outstanding, such
+    /// messages are strays of a closed subscription and are dropped.
+    fn admit(&mut self, response: KalshiWebsocketResponse) -> Vec<KalshiWebsocketResponse> {
+        let awaiting_ack = !self.pending.is_empty() || !self.replays.is_empty();
+        let mut ready = Vec::new();
+        match (&response, response.sid()) {
+            (
+                KalshiWebsocketResponse::Unsubscribed { .. } | KalshiWebsocketResponse::Ok { .. },
+                _,
+            )
+            | (_, None) => ready.push(response),
+            (KalshiWebsocketResponse::Subscribed { msg, .. }, _) => {
+                let held = self.held.remove(&msg.sid).unwrap_or_default();
+                ready.push(response);
+                ready.extend(held);
+            }
+            (_, Some(sid)) if self.server_to_client.contains_key(&sid) => ready.push(response),
+            (_, Some(sid)) if awaiting_ack => {
+                let held = self.held.values().map(Vec::len).sum::<usize>();
+                if held < MAX_HELD_MESSAGES {
+                    self.held.entry(sid).or_default().push(response);
+                } else {
+                    tracing::warn!("Dropping message for unacknowledged sid {}", sid);
+                }
+            }
+            (_, Some(sid)) => tracing::debug!("Dropping message for unknown sid {}", sid),
+        }
+        if !awaiting_ack && !self.held.is_empty() {
+            tracing::warn!(
+                "Dropping messages for sids that were never acknowledged: {:?}",
+                self.held.keys().collect::<Vec<_>>()
+            );
+            self.held.clear();
+        }
+        ready
+    }
+
+    /// Updates bookkeeping for an incoming message and rewrites its sid for the client.
+    ///
+    /// Returns `false` for acknowledgements of internal resubscribes, which the client
+    /// never asked for and should not see.
+    fn process_response(&mut self, response: &mut KalshiWebsocketResponse) -> bool {
+        match response {
+            KalshiWebsocketResponse::Subscribed { id, msg } => {
+                let server_sid = msg.sid;
+                if let Some(client_sid) = id.and_then(|id| self.replays.remove(&id)) {
+                    if let Some(sub) = self.active.get_mut(&client_sid) {
+                        sub.server_sid = server_sid;
+                    }
+                    self.server_to_client.insert(server_sid, client_sid);
+                    return false;
+                }
+                let Some(cmd_id) = *id else {
+                    return true;
+                };
+                let Some(pending) = self.pending.get_mut(&cmd_id) else {
+                    return true;
+                };
+                pending.channels.retain(|c| *c != msg.channel);
+                let params = KalshiSubscribeCommandParams {
+                    channels: vec![msg.channel.clone()],
+                    ..pending.clone()
+                };
+                if pending.channels.is_empty() {
+                    self.pending.remove(&cmd_id);
+                }
+                let client_sid = if self.active.contains_key(&server_sid) {
+                    self.active.keys().max().copied().unwrap_or(0) + 1
+                } else {
+                    server_sid
+                };
+                self.active
+                    .insert(client_sid, ActiveSubscription { params, server_sid });
+                self.server_to_client.insert(server_sid, client_sid);
+                self.publish(client_sid);
+                msg.sid = client_sid;
+            }
+            KalshiWebsocketResponse::Unsubscribed {
+                id: Some(id), sid, ..
+            } if self.resync_unsubscribes.remove(id) => {
+                self.server_to_client.remove(sid);
+                return false;
+            }
+            KalshiWebsocketResponse::Unsubscribed { sid, .. } => {
+                let client_sid = self.client_sid(*sid);
+                self.server_to_client.remove(sid);
+                self.active.remove(&client_sid);
+                self.publish(client_sid);
+                *sid = client_sid;
+            }
+            KalshiWebsocketResponse::Error { id: Some(id), .. } => {
+                self.pending.remove(id);
+                self.updates.remove(id);
+                if let Some(client_sid) = self.replays.remove(id) {
+                    tracing::warn!(
+                        "Failed to restore subscription {} after reconnect",
+                        client_sid
+                    );
+                    self.active.remove(&client_sid);
+                    self.publish(client_sid);
+                }
+            }
+            KalshiWebsocketResponse::OrderbookSnapshot { sid, seq, .. } => {
+                *sid = self.client_sid(*sid);
+                self.record_seq(*sid, *seq);
+                if self.resyncing.remove(sid) {
+                    let market_tickers = self
+                        .active
+                        .get(sid)
+                        .map(|sub| sub.params.tickers())
+                        .unwrap_or_default();
+                    self.notices.push(KalshiWebsocketResponse::Resynced {
+                        sid: *sid,
+                        market_tickers,
+                    });
+                }
+            }
+            KalshiWebsocketResponse::OrderbookDelta { sid, seq, .. } => {
+                *sid = self.client_sid(*sid);
+                if self.resyncing.contains(sid) {
+                    return false;
+                }
+                if !self.check_seq(*sid, *seq, KalshiChannel::OrderbookDelta) {
+                    tracing::warn!("Orderbook sequence gap on sid {}; resubscribing", sid);
+                    self.resync(*sid);
+                    return false;
+                }
+            }
+            KalshiWebsocketResponse::OrderGroupUpdates { sid, seq, .. } => {
+                *sid = self.client_sid(*sid);
+                self.check_seq(*sid, *seq, KalshiChannel::OrderGroupUpdates);
+            }
+            KalshiWebsocketResponse::Ok { id, sid, msg, .. } => {
+                if let Some((client_sid, update)) = id.and_then(|id| self.updates.remove(&id)) {
+                    if let Some(sub) = self.active.get_mut(&client_sid) {
+                        sub.params.apply_update(&update);
+                    }
+                    self.publish(client_sid);
+                }
+                if let Some(sid) = sid.as_mut() {
+                    *sid = self.client_sid(*sid);
+                }
+                if let Some(KalshiOkPayload::Subscriptions(subscriptions)) = msg {
+                    for sub in subscriptions.iter_mut() {
+                        sub.sid = self.client_sid(sub.sid);
+                    }
+                }
+            }
+            KalshiWebsocketResponse::Unknown { raw } => {
+                let server_sid = raw.get("sid").and_then(|sid| sid.as_u64());
+                if let Some(server_sid) = server_sid.and_then(|sid| u32::try_from(sid).ok()) {
+                    raw["sid"] = self.client_sid(server_sid).into();
+                }
+            }
+            _ => {
+                if let Some(sid) = response.sid_mut() {
+                    *sid = self.client_sid(*sid);
+                }
+            }
+        }
+        true
+    }
+
+    /// Checks that `seq` directly follows the last sequence number of `client_sid` and
+    /// records it.
+    ///
+    /// Queues a [`SequenceAnomaly`](KalshiWebsocketResponse::SequenceAnomaly) notice and
+    /// returns `false` when it does not.
+    fn check_seq(&mut self, client_sid: u32, seq: u32, channel: KalshiChannel) -> bool {
+        let expected = self
+            .sequences
+            .get(&client_sid)
+            .map(|last| last.wrapping_add(1));
+        self.record_seq(client_sid, seq);
+        match expected {
+            Some(expected) if seq != expected => {
+                tracing::warn!(
+                    "Sequence anomaly on sid {}: expected {}, got {}",
+                    client_sid,
+                    expected,
+                    seq
+                );
+                self.notices.push(KalshiWebsocketResponse::SequenceAnomaly {
+                    sid: client_sid,
+                    channel,
+                    expected,
+                    received: seq,
+                });
+                false
+            }
+            _ => true,
+        }
+    }
+
+    fn record_seq(&mut self, client_sid: u32, seq: u32) {
+        self.sequences.insert(client_sid, seq);
+        self.last_seqs.write().unwrap().insert(client_sid, seq);
+    }
+
+    /// Queues an unsubscribe and resubscribe for `client_sid` to force a fresh snapshot.
+    ///
+    /// Deltas are dropped until the new snapshot arrives.
+    fn resync(&mut self, client_sid: u32) {
+        if !self.active.contains_key(&client_sid) {
+            return;
+        }
+        instrumentation::orderbook_resynced();
+        self.resyncing.insert(client_sid);
+        self.sequences.remove(&client_sid);
+        self.resubscribe(client_sid);
+    }
+
+    /// Queues an unsubscribe and resubscribe for `client_sid`, keeping its client sid.
+    fn resubscribe(&mut self, client_sid: u32) {
+        let Some(sub) = self.active.get(&client_sid) else {
+            return;
+        };
+        let unsubscribe_id = self.next_id();
+        self.resync_unsubscribes.insert(unsubscribe_id);
+        self.outgoing.push(KalshiCommand::Unsubscribe {
+            id: unsubscribe_id,
+            params: KalshiUnsubscribeCommandParams {
+                sids: vec![sub.server_sid],
+            },
+        });
+
+        let subscribe_id = self.next_id();
+        self.replays.insert(subscribe_id, client_sid);
+        self.outgoing.push(KalshiCommand::Subscribe {
+            id: subscribe_id,
+            params: sub.params.clone(),
+        });
+    }
+
+    /// Records that a message arrived for the subscription it belongs to.
+    fn record_activity(&mut self, response: &KalshiWebsocketResponse) {
+        if let Some(sid) = response.sid() {
+            self.mark_alive(sid);
+        }
+    }
+
+    /// Records that a frame for `server_sid` arrived but was not decoded, e.g. because
+    /// the ticker allowlist dropped it. Frames for sids not mapped yet are ignored.
+    fn record_server_activity(&mut self, server_sid: u32) {
+        if let Some(&client_sid) = self.server_to_client.get(&server_sid) {
+            self.mark_alive(client_sid);
+        }
+    }
+
+    fn mark_alive(&mut self, client_sid: u32) {
+        self.last_message.insert(client_sid, Instant::now());
+        self.stale.remove(&client_sid);
+    }
+
+    /// Reports subscriptions that have been silent past their watchdog timeout.
+    fn check_stale(&mut self) {
+        let now = Instant::now();
+        self.last_message
+            .retain(|sid, _| self.active.contains_key(sid));
+        self.stale.retain(|sid| self.active.contains_key(sid));
+
+        let mut stale = Vec::new();
+        for (sid, sub) in &self.active {
+            let Some(channel) = sub.params.channels.first() else {
+                continue;
+            };
+            let Some(timeout) = self.watchdog.timeout_for(channel) else {
+                continue;
+            };
+            let idle = now.duration_since(*self.last_message.entry(*sid).or_insert(now));
+            if idle >= timeout && !self.stale.contains(sid) {
+                stale.push((*sid, channel.clone(), sub.params.tickers(), idle));
+            }
+        }
+
+        let resubscribe = self.watchdog.resubscribe;
+        for (sid, channel, market_tickers, idle) in stale {
+            tracing::warn!("No messages on sid {} ({:?}) for {:?}", sid, channel, idle);
+            if !resubscribe {
+                self.stale.insert(sid);
+            } else if channel == KalshiChannel::OrderbookDelta {
+                self.resync(sid);
+                self.last_message.insert(sid, now);
+            } else {
+                self.resubscribe(sid);
+                self.last_message.insert(sid, now);
+            }
+            self.notices.push(KalshiWebsocketResponse::Stale {
+                sid,
+                channel,
+                market_tickers,
+                idle,
+                resubscribed: resubscribe,
+            });
+        }
+    }
+
+    /// Builds the commands that restore every subscription on a fresh connection.
+    fn queue_replay(&mut self) {
+        self.server_to_client.clear();
+        self.held.clear();
+        // Silence while disconnected says nothing about the feed itself.
+        self.last_message.clear();
+        self.replays.clear();
+        self.updates.clear();
+        self.resync_unsubscribes.clear();
+        self.sequences.clear();
+        self.outgoing.clear();
+        for (client_sid, sub) in &self.active {
+            let id = self.next_id();
+            self.replays.insert(id, *client_sid);
+            self.outgoing.push(KalshiCommand::Subscribe {
+                id,
+                params: sub.params.clone(),
+            });
+        }
+        // Subscribe commands that were never acknowledged are sent again as-is.
+        for (id, params) in &self.pending {
+            self.outgoing.push(KalshiCommand::Subscribe {
+                id: *id,
+                params: params.clone(),
+            });
+        }
+    }
+}
+
+/// Why a single connection's message loop stopped.
+enum ConnectionExit {
+    /// The client asked to close, or nobody is listening anymore.
+    Shutdown,
+    /// The connection dropped or failed.
+    Disconnected,
+}
+
+/// State the websocket task keeps across connections.
+struct HandlerContext {
+    /// Client used to open new connections.
+    kalshi: Kalshi,
+    reconnect: Option<ReconnectPolicy>,
+    state: SubscriptionState,
+    shutdown: watch::Receiver<bool>,
+    /// Whether a connection is currently open, shared with the client.
+    connected: watch::Sender<bool>,
+    hooks: Arc<FrameHooks>,
     from_kalshi_tx: Sender<Result<KalshiWebsocketResponse, KalshiWebsocketError>>,
-    mut to_kalshi_rx: UnboundedReceiver<KalshiCommand>,
-) {
+    to_kalshi_rx: UnboundedReceiver<KalshiCommand>,
+}
+
+async fn kalshi_ws_handler(context: HandlerContext, mut stream: WsStream) {
+    let HandlerContext {
+        mut kalshi,
+        reconnect,
+        mut state,
+        mut shutdown,
+        connected,
+        hooks,
+        from_kalshi_tx,
+        mut to_kalshi_rx,
+    } = context;
+    loop {
+        let exit = run_connection(
+            stream,
+            kalshi.ws_keepalive,
+            &mut state,
+            &mut shutdown,
+            &hooks,
+            &from_kalshi_tx,
+            &mut to_kalshi_rx,
+        )
+        .await;
+        connected.send_replace(false);
+        let policy = match (exit, &reconnect) {
+            (ConnectionExit::Disconnected, Some(policy)) => policy,
+            (ConnectionExit::Disconnected, None) => {
+                from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
+                return;
+            }
+            (ConnectionExit::Shutdown, _) => return,
+        };
+        let notify = |event| {
+            from_kalshi_tx.send(Ok(KalshiWebsocketResponse::Reconnect(event)));
+        };
+        notify(ReconnectEvent::Disconnected);
+
+        let mut attempt = 0;
+        stream = loop {
+            if from_kalshi_tx.receiver_count() == 0 {
+                return;
+            }
+            if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                tracing::error!("Websocket reconnect gave up after {} attempts", attempt);
+                notify(ReconnectEvent::GaveUp { attempts: attempt });
+                if let Some(hook) = &policy.on_give_up {
+                    hook(attempt);
+                }
+                from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
+                return;
+            }
+            attempt += 1;
+            let delay = policy.delay(attempt);
+            notify(ReconnectEvent::Attempting { attempt, delay });
+            tracing::warn!("Websocket disconnected, reconnecting in {:?}", delay);
+            let connect = async {
+                sleep(delay).await;
+                open_stream(&mut kalshi).await
+            };
+            select_biased! {
+                _ = wait_for_shutdown(&mut shutdown).fuse() => {
+                    from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
+                    return;
+                }
+                res = connect.fuse() => match res {
+                    Ok(stream) => break stream,
+                    Err(e) => {
+                        tracing::warn!("Websocket reconnect failed: {}", e);
+                        notify(ReconnectEvent::AttemptFailed { attempt, error: e.clone() });
+                        if let KalshiWebsocketError::AuthError(_) = e {
+                            // Retrying will not fix credentials, so consumers need to know.
+                            from_kalshi_tx.send(Err(e));
+                        }
+                    }
+                }
+            }
+        };
+        instrumentation::reconnected();
+        connected.send_replace(true);
+        from_kalshi_tx.send(Ok(KalshiWebsocketResponse::Reconnect(
+            ReconnectEvent::Reconnected { attempts: attempt },
+        )));
+        tracing::info!(
+            "Websocket reconnected, restoring {} subscriptions",
+            state.active.len()
+        );
+        // Sent first thing by `run_connection`; a failed send counts as a disconnect.
+        state.queue_replay();
+    }
+}
+
+/// Runs the message loop for one connection until it closes or the client shuts down.
+async fn run_connection(
+    stream: WsStream,
+    keepalive: KeepaliveConfig,
+    state: &mut SubscriptionState,
+    shutdown: &mut watch::Receiver<bool>,
+    hooks: &FrameHooks,
+    from_kalshi_tx: &Sender<Result<KalshiWebsocketResponse, KalshiWebsocketError>>,
+    to_kalshi_rx: &mut UnboundedReceiver<KalshiCommand>,
+) -> ConnectionExit {
     let mut stream = Box::pin(stream.fuse());
-    let mut heartbeat = interval(Duration::from_secs(10));
+    let mut heartbeat = interval(keepalive.ping_interval);
     heartbeat.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut last_seen = Instant::now();
+    let mut watchdog = interval(WATCHDOG_CHECK_INTERVAL);
+    watchdog.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    'out: loop {
+    if send_outgoing(&mut stream, state, hooks, from_kalshi_tx)
+        .await
+        .is_err()
+    {
+        return ConnectionExit::Disconnected;
+    }
+
+    loop {
         select_biased! {
             cmd = to_kalshi_rx.recv().fuse() => {
                 match cmd {
-                    Some(cmd) => {
-                        match serde_json::to_string(&cmd) {
+                    Some(KalshiCommand::End) | None => {
+                        stream.close().await;
+                        from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
+                        return ConnectionExit::Shutdown;
+                    }
+                    Some(KalshiCommand::Resync { sid }) => {
+                        state.resync(sid);
+                        if send_outgoing(&mut stream, state, hooks, from_kalshi_tx).await.is_err() {
+                            return ConnectionExit::Disconnected;
+                        }
+                    }
+                    Some(mut cmd) => {
+                        state.prepare_command(&mut cmd);
+                        match hooks.encode(&cmd) {
                             Ok(msg) => {
-                                stream.send(Message::text(msg)).await.unwrap();
+                                if let Err(e) = stream.send(Message::text(msg)).await {
+                                    from_kalshi_tx.send(Err(KalshiWebsocketError::WebSocketError(e.to_string())));
+                                    return ConnectionExit::Disconnected;
+                                }
                             },
                             Err(e) => {
-                                from_kalshi_tx.send(Err(KalshiWebsocketError::SerializationError(e.to_string())));
+                                from_kalshi_tx.send(Err(e));
                             }
                         }
-
-                    },
-                    _ => {
-                        from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
-                        break 'out;
                     }
                 }
             }
+            // Commands take priority, so anything queued before the shutdown is sent first.
+            _ = wait_for_shutdown(shutdown).fuse() => {
+                stream.close().await;
+                from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
+                return ConnectionExit::Shutdown;
+            }
             _ = heartbeat.tick().fuse() => {
+                if last_seen.elapsed() >= keepalive.timeout {
+                    tracing::warn!(
+                        "No websocket traffic for {:?}, dropping the connection",
+                        last_seen.elapsed()
+                    );
+                    return ConnectionExit::Disconnected;
+                }
                 if let Err(e) = stream.send(Message::Ping(vec![])).await {
                     from_kalshi_tx.send(Err(KalshiWebsocketError::WebSocketError(e.to_string())));
+                    return ConnectionExit::Disconnected;
+                }
+            }
+            _ = watchdog.tick().fuse() => {
+                state.check_stale();
+                for notice in state.notices.drain(..) {
+                    from_kalshi_tx.send(Ok(notice));
+                }
+                if send_outgoing(&mut stream, state, hooks, from_kalshi_tx).await.is_err() {
+                    return ConnectionExit::Disconnected;
                 }
             }
-            item = stream.select_next_some() => {
+            item = stream.next() => {
+                last_seen = Instant::now();
                 match item {
-                    Ok(msg) => {
-                        match msg {
-                            Message::Text(text) => {
-                                match serde_json::from_str::<KalshiWebsocketResponse>(&text) {
-                                    Ok(res) => { from_kalshi_tx.send(Ok(res)); },
-                                    Err(e) => { from_kalshi_tx.send(Err(KalshiWebsocketError::SerializationError(e.to_string()))); },
-                                };
-                            },
-                            Message::Close(_) => {
-                                from_kalshi_tx.send(Err(KalshiWebsocketError::ConnectionClosed));
-                                break 'out;
+                    Some(Ok(Message::Text(text))) => {
+                        hooks.inspect(FrameDirection::Inbound, &text);
+                        if let Some(peek) = filtered_out(&text, hooks) {
+                            // A filtered frame still shows that its subscription is alive.
+                            if let Some(sid) = peek.sid {
+                                state.record_server_activity(sid);
                             }
-                            _ => {}
+                            continue;
                         }
+                        match KalshiWebsocketResponse::from_frame(&text) {
+                            Ok(res) => {
+                                instrumentation::message_received(&res);
+                                for mut res in state.admit(res) {
+                                    let forward = state.process_response(&mut res);
+                                    for notice in state.notices.drain(..) {
+                                        from_kalshi_tx.send(Ok(notice));
+                                    }
+                                    if forward {
+                                        state.record_activity(&res);
+                                        from_kalshi_tx.send(Ok(res));
+                                    }
+                                }
+                                if send_outgoing(&mut stream, state, hooks, from_kalshi_tx).await.is_err() {
+                                    return ConnectionExit::Disconnected;
+                                }
+                            },
+                            Err(e) => {
+                                instrumentation::deserialization_failed();
+                                from_kalshi_tx.send(Err(KalshiWebsocketError::SerializationError(e.to_string())));
+                            },
+                        };
                     },
-                    Err(e) => {
-                       from_kalshi_tx.send(Err(KalshiWebsocketError::WebSocketError(e.to_string())));
+                    Some(Ok(Message::Close(_))) | None => return ConnectionExit::Disconnected,
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        from_kalshi_tx.send(Err(KalshiWebsocketError::WebSocketError(e.to_string())));
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Sends the internal commands queued on `state`.
+///
+/// Fails once the connection can no longer be written to.
+async fn send_outgoing<S>(
+    stream: &mut S,
+    state: &mut SubscriptionState,
+    hooks: &FrameHooks,
+    from_kalshi_tx: &Sender<Result<KalshiWebsocketResponse, KalshiWebsocketError>>,
+) -> Result<(), ()>
+where
+    S: futures_util::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+{
+    for cmd in std::mem::take(&mut state.outgoing) {
+        let msg = match hooks.encode(&cmd) {
+            Ok(msg) => msg,
+            Err(e) => {
+                from_kalshi_tx.send(Err(e));
+                continue;
+            }
+        };
+        if let Err(e) = stream.send(Message::text(msg)).await {
+            from_kalshi_tx.send(Err(KalshiWebsocketError::WebSocketError(e.to_string())));
+            return Err(());
+        }
+    }
+    Ok(())
+}
+
+/// Resolves once a shutdown is requested or every [`ShutdownHandle`] is gone.
+async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>) {
+    while !*shutdown.borrow_and_update() {
+        if shutdown.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Feeds responses to `ack` until it reports that command `cmd_id` is fully acknowledged.
+///
+/// An `error` response carrying `cmd_id` fails the wait, as does the connection closing.
+async fn wait_for_ack(
+    mut responses: Receiver<Result<KalshiWebsocketResponse, KalshiWebsocketError>>,
+    cmd_id: u32,
+    mut ack: impl FnMut(KalshiWebsocketResponse) -> bool,
+) -> Result<(), KalshiWebsocketError> {
+    loop {
+        match responses.recv().await {
+            Ok(Ok(KalshiWebsocketResponse::Error { id: Some(id), msg })) if id == cmd_id => {
+                return Err(KalshiWebsocketError::CommandRejected(msg));
+            }
+            Ok(Ok(res)) => {
+                if ack(res) {
+                    return Ok(());
+                }
+            }
+            Ok(Err(KalshiWebsocketError::ConnectionClosed)) | Err(RecvError::Closed) => {
+                return Err(KalshiWebsocketError::ConnectionClosed);
+            }
+            Ok(Err(_)) | Err(RecvError::Lagged(_)) => {}
+        }
+    }
+}
+
+/// Frame types that are never dropped by the ticker allowlist.
+const UNFILTERED_TYPES: [&str; 6] = [
+    "subscribed",
+    "unsubscribed",
+    "ok",
+    "error",
+    "orderbook_snapshot",
+    "orderbook_delta",
+];
+
+/// The fields of a raw frame the ticker allowlist looks at.
+///
+/// Strings are borrowed from the frame unless they contain escapes, and every other
+/// field is skipped without being decoded.
+#[derive(Deserialize)]
+struct FramePeek<'a> {
+    #[serde(rename = "type", borrow)]
+    kind: Cow<'a, str>,
+    sid: Option<u32>,
+    #[serde(default, borrow)]
+    msg: MessagePeek<'a>,
+}
+
+#[derive(Default, Deserialize)]
+struct MessagePeek<'a> {
+    #[serde(default, borrow)]
+    market_ticker: Option<Cow<'a, str>>,
+}
+
+/// Checks a raw frame against the ticker allowlist without fully deserializing it.
+///
+/// Returns the peeked fields of a frame the allowlist drops, or `None` if the frame
+/// must be delivered. Frames that do not have the expected shape are delivered, so
+/// that full decoding can report them.
+fn filtered_out<'a>(text: &'a str, hooks: &FrameHooks) -> Option<FramePeek<'a>> {
+    let allowlist = hooks.ticker_allowlist.read().unwrap();
+    let allowlist = allowlist.as_ref()?;
+    let peek: FramePeek = serde_json::from_str(text).ok()?;
+    if UNFILTERED_TYPES.contains(&peek.kind.as_ref()) {
+        return None;
+    }
+    let ticker = peek.msg.market_ticker.as_deref()?;
+    (!allowlist.contains(ticker)).then_some(peek)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> SubscriptionState {
+        SubscriptionState::new(
+            Arc::new(AtomicU32::new(100)),
+            Arc::default(),
+            Arc::default(),
+            WatchdogConfig::default(),
+        )
+    }
+
+    fn subscribe(state: &mut SubscriptionState, id: u32, channel: KalshiChannel, ticker: &str) {
+        state.prepare_command(&mut KalshiCommand::Subscribe {
+            id,
+            params: KalshiSubscribeCommandParams {
+                channels: vec![channel],
+                market_ticker: Some(ticker.to_string()),
+                ..Default::default()
+            },
+        });
+    }
+
+    fn subscribed(id: u32, channel: &str, sid: u32) -> String {
+        format!(
+            r#"{{"type": "subscribed", "id": {}, "msg": {{"channel": "{}", "sid": {}}}}}"#,
+            id, channel, sid
+        )
+    }
+
+    fn trade(sid: u32) -> String {
+        format!(
+            r#"{{"type": "trade", "sid": {}, "msg": {{
+                "trade_id": "t", "market_ticker": "KXA-1", "yes_price": 40,
+                "yes_price_dollars": "0.40", "no_price": 60, "no_price_dollars": "0.60",
+                "count": 1, "count_fp": "1.00", "taker_side": "yes", "ts": 1
+            }}}}"#,
+            sid
+        )
+    }
+
+    /// Runs a frame through the handler's bookkeeping and returns what reaches the client.
+    fn deliver(state: &mut SubscriptionState, frame: &str) -> Vec<KalshiWebsocketResponse> {
+        let response = KalshiWebsocketResponse::from_frame(frame).unwrap();
+        let mut delivered = Vec::new();
+        for mut res in state.admit(response) {
+            if state.process_response(&mut res) {
+                delivered.push(res);
+            }
+        }
+        delivered
+    }
+
+    /// Takes the queued resubscribe commands and returns their command ids.
+    fn replay_ids(state: &mut SubscriptionState) -> Vec<u32> {
+        state
+            .outgoing
+            .drain(..)
+            .filter_map(|cmd| match cmd {
+                KalshiCommand::Subscribe { id, .. } => Some(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn client_sids_survive_reconnects() {
+        let mut state = state();
+        subscribe(&mut state, 1, KalshiChannel::Trade, "KXA-1");
+        let acked = deliver(&mut state, &subscribed(1, "trade", 3));
+        assert!(
+            matches!(acked[..], [KalshiWebsocketResponse::Subscribed { ref msg, .. }] if msg.sid == 3)
+        );
+
+        state.queue_replay();
+        let [replay] = replay_ids(&mut state)[..] else {
+            panic!("expected one resubscribe");
+        };
+        // The replay acknowledgement is internal and never reaches the client.
+        assert!(deliver(&mut state, &subscribed(replay, "trade", 9)).is_empty());
+
+        let delivered = deliver(&mut state, &trade(9));
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].sid(), Some(3));
+
+        let mut unsubscribe = KalshiCommand::Unsubscribe {
+            id: 2,
+            params: KalshiUnsubscribeCommandParams { sids: vec![3] },
+        };
+        state.prepare_command(&mut unsubscribe);
+        let KalshiCommand::Unsubscribe { params, .. } = unsubscribe else {
+            unreachable!();
+        };
+        assert_eq!(params.sids, vec![9]);
+        assert_eq!(
+            state.registry.read().unwrap().keys().collect::<Vec<_>>(),
+            vec![&3]
+        );
+    }
+
+    #[test]
+    fn messages_are_held_until_the_subscribe_ack() {
+        let mut state = state();
+        subscribe(&mut state, 1, KalshiChannel::Trade, "KXA-1");
+
+        // Kalshi can send data before the acknowledgement that announces its sid.
+        assert!(deliver(&mut state, &trade(4)).is_empty());
+        let delivered = deliver(&mut state, &subscribed(1, "trade", 4));
+        assert!(matches!(
+            delivered[..],
+            [
+                KalshiWebsocketResponse::Subscribed { .. },
+                KalshiWebsocketResponse::Trade { sid: 4, .. }
+            ]
+        ));
+
+        // With no subscribe outstanding, messages for unknown sids are dropped.
+        assert!(deliver(&mut state, &trade(5)).is_empty());
+        assert!(state.held.is_empty());
+    }
+
+    #[test]
+    fn replay_restores_every_subscription() {
+        let mut state = state();
+        subscribe(&mut state, 1, KalshiChannel::Trade, "KXA-1");
+        subscribe(&mut state, 2, KalshiChannel::Ticker, "KXB-1");
+        subscribe(&mut state, 3, KalshiChannel::Fill, "KXC-1");
+        deliver(&mut state, &subscribed(1, "trade", 1));
+        deliver(&mut state, &subscribed(2, "ticker", 2));
+
+        state.queue_replay();
+        let mut ids = replay_ids(&mut state);
+        ids.sort();
+        // One fresh command per active subscription, then the unacknowledged one as-is.
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0], 3);
+        let (first, second) = (ids[1], ids[2]);
+        let replayed: HashSet<u32> = [state.replays[&first], state.replays[&second]].into();
+        assert_eq!(replayed, HashSet::from([1, 2]));
+
+        let trade_replay = if state.replays[&first] == 1 {
+            first
+        } else {
+            second
+        };
+        let ticker_replay = if trade_replay == first { second } else { first };
+        deliver(&mut state, &subscribed(trade_replay, "trade", 20));
+        deliver(&mut state, &subscribed(ticker_replay, "ticker", 21));
+        assert_eq!(state.server_sid(1), 20);
+        assert_eq!(state.server_sid(2), 21);
+        assert!(state.replays.is_empty());
+
+        // The pending subscribe is acknowledged as a new subscription.
+        let acked = deliver(&mut state, &subscribed(3, "fill", 22));
+        assert!(
+            matches!(acked[..], [KalshiWebsocketResponse::Subscribed { ref msg, .. }] if msg.sid == 22)
+        );
+        let mut sids: Vec<u32> = state.registry.read().unwrap().keys().copied().collect();
+        sids.sort();
+        assert_eq!(sids, vec![1, 2, 22]);
+    }
+
+    fn allowlist(tickers: &[&str]) -> FrameHooks {
+        let hooks = FrameHooks::default();
+        *hooks.ticker_allowlist.write().unwrap() =
+            Some(tickers.iter().map(|t| t.to_string()).collect());
+        hooks
+    }
+
+    #[test]
+    fn allowlist_reads_escaped_tickers() {
+        let hooks = allowlist(&["KX\"A"]);
+        let frame = r#"{"type": "ticker", "sid": 2, "msg": {"market_ticker": "KX\"A"}}"#;
+        assert!(filtered_out(frame, &hooks).is_none());
+
+        let frame = r#"{"type": "ticker", "sid": 2, "msg": {"market_ticker": "KX\"B"}}"#;
+        assert_eq!(filtered_out(frame, &hooks).unwrap().sid, Some(2));
+    }
+
+    #[test]
+    fn allowlist_ignores_field_names_inside_strings() {
+        let hooks = allowlist(&["KXA"]);
+        let frame = r#"{"type": "trade", "sid": 3, "msg": {
+            "note": "market_ticker", "title": "\"type\": \"ok\"", "market_ticker": "KXB"
+        }}"#;
+        assert_eq!(filtered_out(frame, &hooks).unwrap().sid, Some(3));
+    }
+
+    #[test]
+    fn allowlist_delivers_frames_without_a_ticker() {
+        let hooks = allowlist(&["KXA"]);
+        let frame = r#"{"type": "event_lifecycle", "sid": 4, "msg": {"event_ticker": "KXB"}}"#;
+        assert!(filtered_out(frame, &hooks).is_none());
+        let frame = r#"{"type": "ok", "id": 1, "msg": [{"channel": "ticker", "sid": 1}]}"#;
+        assert!(filtered_out(frame, &hooks).is_none());
+        assert!(filtered_out("not json", &hooks).is_none());
+    }
+}
@@ -22,37 +22,87 @@ pub enum KalshiCommand {
     },
     /// Internal signal to close the WebSocket connection.
     End,
+    /// Internal signal to resubscribe an orderbook subscription for a fresh snapshot.
+    #[serde(skip)]
+    Resync {
+        sid: u32,
+    },
 }
 
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct KalshiSubscribeCommandParams {
     pub channels: Vec<KalshiChannel>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_ticker: Option<String>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_tickers: Option<Vec<String>>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_id: Option<String>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_ids: Option<Vec<String>>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub send_initial_snapshot: Option<bool>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_ticker_ack: Option<bool>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shard_factor: Option<u32>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<u32>,
 }
 
+impl KalshiSubscribeCommandParams {
+    /// Returns every market ticker the subscription covers.
+    pub(crate) fn tickers(&self) -> Vec<String> {
+        self.market_ticker
+            .iter()
+            .chain(self.market_tickers.iter().flatten())
+            .cloned()
+            .collect()
+    }
+
+    /// Drops one market from the subscription.
+    pub(crate) fn remove_ticker(&mut self, ticker: &str) {
+        if self.market_ticker.as_deref() == Some(ticker) {
+            self.market_ticker = None;
+        }
+        if let Some(tickers) = self.market_tickers.as_mut() {
+            tickers.retain(|t| t != ticker);
+        }
+    }
+
+    /// Applies an acknowledged `update_subscription` so a replay restores the same markets.
+    pub(crate) fn apply_update(&mut self, update: &KalshiUpdateSubscriptionCommandParams) {
+        let mut tickers = self.tickers();
+        let changed = update
+            .market_ticker
+            .iter()
+            .chain(update.market_tickers.iter().flatten());
+        match update.action {
+            KalshiUpdateSubscriptionAction::AddMarkets => {
+                for ticker in changed {
+                    if !tickers.contains(ticker) {
+                        tickers.push(ticker.clone());
+                    }
+                }
+            }
+            KalshiUpdateSubscriptionAction::DeleteMarkets => {
+                let changed: Vec<&String> = changed.collect();
+                tickers.retain(|ticker| !changed.contains(&ticker));
+            }
+        }
+        self.market_ticker = None;
+        self.market_tickers = Some(tickers);
+    }
+}
+
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct KalshiUnsubscribeCommandParams {
     pub sids: Vec<u32>,
@@ -61,25 +111,25 @@ pub struct KalshiUnsubscribeCommandParams {
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct KalshiUpdateSubscriptionCommandParams {
     pub action: KalshiUpdateSubscriptionAction,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sid: Option<u32>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sids: Option<[u32; 1]>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_ticker: Option<String>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_tickers: Option<Vec<String>>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_id: Option<String>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market_ids: Option<Vec<String>>,
-    
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub send_initial_snapshot: Option<bool>,
 }
@@ -90,4 +140,4 @@ pub enum KalshiUpdateSubscriptionAction {
     #[default]
     AddMarkets,
     DeleteMarkets,
-}
\ No newline at end of file
+}
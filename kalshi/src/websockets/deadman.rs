@@ -0,0 +1,174 @@
+use super::{client::KalshiWebsocketClient, KalshiChannel};
+use crate::{Kalshi, MAX_ORDERS_PER_BATCH_CANCEL};
+use std::time::Duration;
+use tokio::{
+    sync::broadcast,
+    task::JoinHandle,
+    time::{interval, Instant, MissedTickBehavior},
+};
+
+/// Delay before a failed cancel is first retried.
+pub const CANCEL_RETRY_INITIAL: Duration = Duration::from_secs(1);
+/// Longest delay between cancel retries.
+pub const CANCEL_RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Emitted by a [`DeadMansSwitch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SafetyEvent {
+    /// The authenticated feed has been down for longer than the timeout.
+    FeedLost { lost_for: Duration },
+    /// Resting orders were cancelled after the feed was lost.
+    OrdersCancelled { count: usize },
+    /// Cancelling resting orders failed, entirely or for some batches. Orders may still
+    /// be live; the switch tries again after `retry_in` while the feed stays down.
+    CancelFailed { error: String, retry_in: Duration },
+    /// The feed is back after the switch fired. The switch is armed again.
+    FeedRestored,
+}
+
+/// Cancels every resting order when the authenticated websocket feed is lost.
+///
+/// The feed counts as lost while the websocket is disconnected or none of the watched
+/// channels (by default `fill` and `market_positions`) has an active subscription. Once
+/// that has lasted for the timeout, the switch cancels every resting order through
+/// [`Kalshi::cancel_all_orders`] and reports it on [`events`](DeadMansSwitch::events).
+/// A failed or partial cancel is retried with exponential backoff, from
+/// [`CANCEL_RETRY_INITIAL`] up to [`CANCEL_RETRY_MAX`], until every resting order has
+/// been cancelled or the feed comes back. Once the cancel succeeds the switch stays
+/// quiet for the rest of the outage.
+///
+/// Closing the client on purpose does not trigger it.
+///
+/// # Example
+/// ```
+/// # fn example(k: kalshi::Kalshi, ws: &kalshi::client::KalshiWebsocketClient) {
+/// use kalshi::deadman::DeadMansSwitch;
+/// use std::time::Duration;
+///
+/// let switch = DeadMansSwitch::new(k, Duration::from_secs(15));
+/// let mut events = switch.events();
+/// switch.spawn(ws);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DeadMansSwitch {
+    rest: Kalshi,
+    timeout: Duration,
+    channels: Vec<KalshiChannel>,
+    events: broadcast::Sender<SafetyEvent>,
+}
+
+impl DeadMansSwitch {
+    /// Creates a switch that cancels orders through `rest` after the feed has been lost
+    /// for `timeout`.
+    pub fn new(rest: Kalshi, timeout: Duration) -> Self {
+        let (events, _) = broadcast::channel(16);
+        DeadMansSwitch {
+            rest,
+            timeout,
+            channels: vec![KalshiChannel::Fill, KalshiChannel::MarketPositions],
+            events,
+        }
+    }
+
+    /// Sets the channels that make up the authenticated feed. The feed is healthy while
+    /// any of them has an active subscription.
+    pub fn channels(mut self, channels: Vec<KalshiChannel>) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Returns a receiver for safety events.
+    pub fn events(&self) -> broadcast::Receiver<SafetyEvent> {
+        self.events.subscribe()
+    }
+
+    /// Spawns the task that watches `client`.
+    ///
+    /// The task ends once the client is closed on purpose.
+    pub fn spawn(&self, client: &KalshiWebsocketClient) -> JoinHandle<()> {
+        let switch = self.clone();
+        let connected = client.connection_status();
+        let registry = client.registry();
+        let shutdown = client.shutdown_handle();
+        let mut check = interval((self.timeout / 4).max(Duration::from_millis(100)));
+        check.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        tokio::spawn(async move {
+            let mut lost_since = None;
+            // Whether the outage was announced, and whether its cancel succeeded.
+            let mut announced = false;
+            let mut fired = false;
+            let mut next_attempt = Instant::now();
+            let mut retry_delay = CANCEL_RETRY_INITIAL;
+            loop {
+                check.tick().await;
+                if shutdown.is_shutdown() {
+                    return;
+                }
+                let healthy = *connected.borrow()
+                    && registry
+                        .read()
+                        .unwrap()
+                        .values()
+                        .any(|sub| switch.channels.contains(&sub.channel));
+                if healthy {
+                    lost_since = None;
+                    if announced {
+                        announced = false;
+                        fired = false;
+                        next_attempt = Instant::now();
+                        retry_delay = CANCEL_RETRY_INITIAL;
+                        let _ = switch.events.send(SafetyEvent::FeedRestored);
+                    }
+                    continue;
+                }
+                let lost_for = lost_since.get_or_insert_with(Instant::now).elapsed();
+                if fired || lost_for < switch.timeout || Instant::now() < next_attempt {
+                    continue;
+                }
+                if !announced {
+                    announced = true;
+                    tracing::error!(
+                        "Authenticated feed lost for {:?}, cancelling all resting orders",
+                        lost_for
+                    );
+                    let _ = switch.events.send(SafetyEvent::FeedLost { lost_for });
+                }
+                let error = match switch.rest.cancel_all_orders().await {
+                    Ok(report) => {
+                        if !report.cancelled.is_empty() || report.is_complete() {
+                            let _ = switch.events.send(SafetyEvent::OrdersCancelled {
+                                count: report.cancelled.len(),
+                            });
+                        }
+                        match report.failed.first() {
+                            None => {
+                                fired = true;
+                                continue;
+                            }
+                            Some(batch) => format!(
+                                "{} batches of up to {} orders failed: {}",
+                                report.failed.len(),
+                                MAX_ORDERS_PER_BATCH_CANCEL,
+                                batch.error
+                            ),
+                        }
+                    }
+                    Err(e) => e.to_string(),
+                };
+                tracing::error!(
+                    "Dead man's switch failed to cancel orders, retrying in {:?}: {}",
+                    retry_delay,
+                    error
+                );
+                let _ = switch.events.send(SafetyEvent::CancelFailed {
+                    error,
+                    retry_in: retry_delay,
+                });
+                next_attempt = Instant::now() + retry_delay;
+                retry_delay = (retry_delay * 2).min(CANCEL_RETRY_MAX);
+            }
+        })
+    }
+}
@@ -0,0 +1,271 @@
+use super::{
+    client::KalshiWebsocketClient,
+    responses::{KalshiAction, KalshiFillMessage, KalshiSide, KalshiWebsocketResponse},
+};
+use crate::{KalshiError, Reloadable, Validate};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tokio::{sync::broadcast, task::JoinHandle};
+
+/// Running exposure for one market or event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Exposure {
+    /// Net contracts held, counted as YES contracts; NO contracts count negative.
+    pub contracts: i64,
+    /// Net amount paid for the contracts, in cents. Sales reduce it.
+    pub notional: i64,
+}
+
+/// Exposure caps checked after every fill. `None` disables a check.
+///
+/// Caps apply to absolute values, so long and short exposure are limited alike.
+/// They are also the thresholds at which [`ExposureAlert`]s are raised.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ExposureLimits {
+    pub max_market_contracts: Option<i64>,
+    pub max_market_notional: Option<i64>,
+    pub max_event_contracts: Option<i64>,
+    pub max_event_notional: Option<i64>,
+}
+
+impl Validate for ExposureLimits {
+    fn validate(&self) -> Result<(), KalshiError> {
+        for (name, limit) in [
+            ("max_market_contracts", self.max_market_contracts),
+            ("max_market_notional", self.max_market_notional),
+            ("max_event_contracts", self.max_event_contracts),
+            ("max_event_notional", self.max_event_notional),
+        ] {
+            if let Some(limit) = limit.filter(|l| *l < 0) {
+                return Err(KalshiError::UserInputError(format!(
+                    "{} must not be negative, got {}",
+                    name, limit
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether an alert concerns a market or an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExposureScope {
+    Market(String),
+    Event(String),
+}
+
+/// Which quantity exceeded its limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExposureMeasure {
+    Contracts,
+    Notional,
+}
+
+/// Emitted for every fill that leaves exposure above a configured limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExposureAlert {
+    pub scope: ExposureScope,
+    pub measure: ExposureMeasure,
+    pub value: i64,
+    pub limit: i64,
+}
+
+impl fmt::Display for ExposureAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, ticker) = match &self.scope {
+            ExposureScope::Market(t) => ("market", t),
+            ExposureScope::Event(t) => ("event", t),
+        };
+        let measure = match self.measure {
+            ExposureMeasure::Contracts => "contracts",
+            ExposureMeasure::Notional => "notional",
+        };
+        write!(
+            f,
+            "{} {} exposure of {} on {} exceeds limit {}",
+            kind, measure, self.value, ticker, self.limit
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct ExposureBook {
+    markets: HashMap<String, Exposure>,
+    events: HashMap<String, Exposure>,
+    /// Explicit market to event mappings, overriding the ticker prefix.
+    market_events: HashMap<String, String>,
+}
+
+/// Tracks exposure per market and per event from the `fill` websocket channel.
+///
+/// Market contracts follow the `post_position` Kalshi reports with each fill, so they
+/// stay correct even if earlier fills were missed. Notional accumulates the cost of
+/// every fill seen.
+///
+/// Fills do not carry their event ticker. Unless registered with
+/// [`map_market`](ExposureTracker::map_market), a market belongs to the event named by
+/// its ticker up to the last `-` (e.g. `KXHIGHNY-25JAN01-B40` to `KXHIGHNY-25JAN01`).
+///
+/// Limits are read from a [`Reloadable`] on every fill, so reloading it changes the
+/// caps without recreating the tracker or losing the exposure recorded so far.
+///
+/// Clones share the same state and alert channel.
+#[derive(Debug, Clone)]
+pub struct ExposureTracker {
+    book: Arc<Mutex<ExposureBook>>,
+    limits: Reloadable<ExposureLimits>,
+    alerts: broadcast::Sender<ExposureAlert>,
+}
+
+impl ExposureTracker {
+    /// Creates a tracker with no exposure, checking fills against `limits`.
+    pub fn new(limits: Reloadable<ExposureLimits>) -> Self {
+        let (alerts, _) = broadcast::channel(256);
+        ExposureTracker {
+            book: Arc::new(Mutex::new(ExposureBook::default())),
+            limits,
+            alerts,
+        }
+    }
+
+    /// Records that `market_ticker` belongs to `event_ticker`.
+    pub fn map_market(&self, market_ticker: impl Into<String>, event_ticker: impl Into<String>) {
+        self.book
+            .lock()
+            .unwrap()
+            .market_events
+            .insert(market_ticker.into(), event_ticker.into());
+    }
+
+    /// Returns a receiver for limit alerts.
+    pub fn alerts(&self) -> broadcast::Receiver<ExposureAlert> {
+        self.alerts.subscribe()
+    }
+
+    /// Applies a fill and returns the alerts it raised, which are also broadcast.
+    pub fn apply(&self, fill: &KalshiFillMessage) -> Vec<ExposureAlert> {
+        let price = match fill.side {
+            KalshiSide::Yes => fill.yes_price as i64,
+            KalshiSide::No => 100 - fill.yes_price as i64,
+        };
+        let cost = match fill.action {
+            KalshiAction::Buy => price * fill.count as i64,
+            KalshiAction::Sell => -price * fill.count as i64,
+        };
+
+        let mut book = self.book.lock().unwrap();
+        let event_ticker = book
+            .market_events
+            .get(&fill.market_ticker)
+            .cloned()
+            .unwrap_or_else(|| event_ticker_of(&fill.market_ticker).to_string());
+
+        let market = book.markets.entry(fill.market_ticker.clone()).or_default();
+        let contracts_delta = fill.post_position as i64 - market.contracts;
+        market.contracts = fill.post_position as i64;
+        market.notional += cost;
+        let market = *market;
+
+        let event = book.events.entry(event_ticker.clone()).or_default();
+        event.contracts += contracts_delta;
+        event.notional += cost;
+        let event = *event;
+        drop(book);
+
+        let limits = *self.limits.current();
+        let checks = [
+            (
+                ExposureScope::Market(fill.market_ticker.clone()),
+                ExposureMeasure::Contracts,
+                market.contracts,
+                limits.max_market_contracts,
+            ),
+            (
+                ExposureScope::Market(fill.market_ticker.clone()),
+                ExposureMeasure::Notional,
+                market.notional,
+                limits.max_market_notional,
+            ),
+            (
+                ExposureScope::Event(event_ticker.clone()),
+                ExposureMeasure::Contracts,
+                event.contracts,
+                limits.max_event_contracts,
+            ),
+            (
+                ExposureScope::Event(event_ticker),
+                ExposureMeasure::Notional,
+                event.notional,
+                limits.max_event_notional,
+            ),
+        ];
+        let alerts: Vec<ExposureAlert> = checks
+            .into_iter()
+            .filter_map(|(scope, measure, value, limit)| {
+                let limit = limit?;
+                (value.abs() > limit).then_some(ExposureAlert {
+                    scope,
+                    measure,
+                    value,
+                    limit,
+                })
+            })
+            .collect();
+        for alert in &alerts {
+            tracing::warn!("{}", alert);
+            // No receivers is fine, alerts are also returned to the caller.
+            let _ = self.alerts.send(alert.clone());
+        }
+        alerts
+    }
+
+    /// Spawns a task that applies every fill received by `client`.
+    ///
+    /// The task ends once the websocket client shuts down.
+    pub fn spawn(&self, client: &KalshiWebsocketClient) -> JoinHandle<()> {
+        let tracker = self.clone();
+        let mut messages = client.stream();
+        tokio::spawn(async move {
+            while let Some(res) = messages.next().await {
+                if let Ok(KalshiWebsocketResponse::Fill { msg, .. }) = res {
+                    tracker.apply(&msg);
+                }
+            }
+        })
+    }
+
+    /// Returns the exposure in a market.
+    pub fn market(&self, market_ticker: &str) -> Exposure {
+        self.book
+            .lock()
+            .unwrap()
+            .markets
+            .get(market_ticker)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the exposure across every market of an event.
+    pub fn event(&self, event_ticker: &str) -> Exposure {
+        self.book
+            .lock()
+            .unwrap()
+            .events
+            .get(event_ticker)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the event part of a market ticker: everything before the last `-`.
+fn event_ticker_of(market_ticker: &str) -> &str {
+    market_ticker
+        .rsplit_once('-')
+        .map_or(market_ticker, |(event, _)| event)
+}
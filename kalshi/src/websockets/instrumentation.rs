@@ -0,0 +1,59 @@
+//! Websocket metrics, recorded through the `metrics` facade when the `metrics` feature
+//! is enabled and compiled away otherwise.
+//!
+//! | Name | Kind | Labels |
+//! |------|------|--------|
+//! | `kalshi_ws_messages_total` | counter | `type` |
+//! | `kalshi_ws_last_ticker_timestamp_seconds` | gauge | |
+//! | `kalshi_ws_deserialization_failures_total` | counter | |
+//! | `kalshi_ws_receiver_lagged_messages_total` | counter | |
+//! | `kalshi_ws_orderbook_resyncs_total` | counter | |
+//! | `kalshi_ws_reconnects_total` | counter | |
+
+use super::responses::KalshiWebsocketResponse;
+
+#[cfg(feature = "metrics")]
+pub(crate) fn message_received(res: &KalshiWebsocketResponse) {
+    metrics::counter!("kalshi_ws_messages_total", "type" => res.message_type()).increment(1);
+    if let KalshiWebsocketResponse::Ticker { .. } = res {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        metrics::gauge!("kalshi_ws_last_ticker_timestamp_seconds").set(now.as_secs_f64());
+    }
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn deserialization_failed() {
+    metrics::counter!("kalshi_ws_deserialization_failures_total").increment(1);
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn receiver_lagged(skipped: u64) {
+    metrics::counter!("kalshi_ws_receiver_lagged_messages_total").increment(skipped);
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn orderbook_resynced() {
+    metrics::counter!("kalshi_ws_orderbook_resyncs_total").increment(1);
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn reconnected() {
+    metrics::counter!("kalshi_ws_reconnects_total").increment(1);
+}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn message_received(_res: &KalshiWebsocketResponse) {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn deserialization_failed() {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn receiver_lagged(_skipped: u64) {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn orderbook_resynced() {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn reconnected() {}
@@ -0,0 +1,79 @@
+use super::{
+    client::KalshiWebsocketClient,
+    responses::{KalshiTickerMessage, KalshiWebsocketResponse},
+};
+use futures_util::StreamExt;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::{sync::watch, task::JoinHandle};
+
+/// The latest ticker message per market, published on `watch` channels.
+///
+/// Where [`KalshiWebsocketClient::receiver`] delivers every message to every consumer,
+/// a watch only keeps the most recent value. Strategy tasks that only care about the
+/// current quote can read it at their own pace and never lag behind.
+///
+/// Clones share the same channels.
+#[derive(Debug, Clone, Default)]
+pub struct LatestTickers {
+    channels: Arc<Mutex<HashMap<String, watch::Sender<Option<KalshiTickerMessage>>>>>,
+}
+
+impl LatestTickers {
+    /// Creates an empty set of channels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns a task that publishes every ticker message received by `client`.
+    ///
+    /// The task ends once the websocket client shuts down.
+    pub fn spawn(&self, client: &KalshiWebsocketClient) -> JoinHandle<()> {
+        let latest = self.clone();
+        let mut messages = client.stream();
+        tokio::spawn(async move {
+            while let Some(res) = messages.next().await {
+                if let Ok(KalshiWebsocketResponse::Ticker { msg, .. }) = res {
+                    latest.publish(msg);
+                }
+            }
+        })
+    }
+
+    /// Publishes a ticker message to the watchers of its market.
+    pub fn publish(&self, msg: KalshiTickerMessage) {
+        let mut channels = self.channels.lock().unwrap();
+        match channels.get(&msg.market_ticker) {
+            Some(sender) => {
+                sender.send_replace(Some(msg));
+            }
+            None => {
+                let ticker = msg.market_ticker.clone();
+                channels.insert(ticker, watch::channel(Some(msg)).0);
+            }
+        }
+    }
+
+    /// Returns a receiver that always holds the latest ticker message for a market.
+    ///
+    /// The value is `None` until the first message for the market arrives.
+    pub fn watch(&self, market_ticker: &str) -> watch::Receiver<Option<KalshiTickerMessage>> {
+        self.channels
+            .lock()
+            .unwrap()
+            .entry(market_ticker.to_string())
+            .or_insert_with(|| watch::channel(None).0)
+            .subscribe()
+    }
+
+    /// Returns the latest ticker message for a market, if one has arrived.
+    pub fn latest(&self, market_ticker: &str) -> Option<KalshiTickerMessage> {
+        self.channels
+            .lock()
+            .unwrap()
+            .get(market_ticker)
+            .and_then(|sender| sender.borrow().clone())
+    }
+}
@@ -1,14 +1,43 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(target_arch = "wasm32")]
+compile_error!("the `websockets` feature is not supported on wasm32; disable default features");
+
+pub mod books;
+
+pub mod builder;
+
+pub mod callbacks;
+
+pub mod channels;
+
 pub mod commands;
 
+pub mod deadman;
+
+pub mod exposure;
+
+mod instrumentation;
+
+pub mod latest;
+
 pub mod client;
 
+pub mod positions;
+
+pub mod replay;
+
+pub mod sharded;
+
 #[allow(dead_code)]
 pub mod responses;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
-#[serde(rename_all = "snake_case")]
+/// A websocket channel.
+///
+/// Channels this crate does not know yet can be subscribed to with
+/// [`Custom`](KalshiChannel::Custom); their messages arrive as
+/// [`Unknown`](responses::KalshiWebsocketResponse::Unknown) raw JSON.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
 pub enum KalshiChannel {
     OrderbookDelta,
     Ticker,
@@ -16,15 +45,35 @@ pub enum KalshiChannel {
     Fill,
     MarketLifecycle,
     MarketLifecycleV2,
+    EventLifecycle,
     MarketPositions,
     Multivariate,
     Communications,
     OrderGroupUpdates,
     UserOrders,
+    /// Any other channel, by the name Kalshi uses for it.
+    Custom(String),
 }
 
 impl KalshiChannel {
-    const fn as_str(&self) -> &'static str {
+    /// Every channel with a named variant.
+    const KNOWN: [KalshiChannel; 12] = [
+        KalshiChannel::OrderbookDelta,
+        KalshiChannel::Ticker,
+        KalshiChannel::Trade,
+        KalshiChannel::Fill,
+        KalshiChannel::MarketLifecycle,
+        KalshiChannel::MarketLifecycleV2,
+        KalshiChannel::EventLifecycle,
+        KalshiChannel::MarketPositions,
+        KalshiChannel::Multivariate,
+        KalshiChannel::Communications,
+        KalshiChannel::OrderGroupUpdates,
+        KalshiChannel::UserOrders,
+    ];
+
+    /// Returns the channel name as Kalshi spells it.
+    pub fn as_str(&self) -> &str {
         match self {
             KalshiChannel::OrderbookDelta => "orderbook_delta",
             KalshiChannel::Ticker => "ticker",
@@ -32,17 +81,103 @@ impl KalshiChannel {
             KalshiChannel::Fill => "fill",
             KalshiChannel::MarketLifecycle => "market_lifecycle",
             KalshiChannel::MarketLifecycleV2 => "market_lifecycle_v2",
+            KalshiChannel::EventLifecycle => "event_lifecycle",
             KalshiChannel::MarketPositions => "market_positions",
             KalshiChannel::Multivariate => "multivariate",
             KalshiChannel::Communications => "communications",
             KalshiChannel::OrderGroupUpdates => "order_group_updates",
             KalshiChannel::UserOrders => "user_orders",
+            KalshiChannel::Custom(name) => name,
         }
     }
 }
 
-impl From<KalshiChannel> for &'static str {
+impl From<&str> for KalshiChannel {
+    fn from(name: &str) -> Self {
+        KalshiChannel::KNOWN
+            .into_iter()
+            .find(|channel| channel.as_str() == name)
+            .unwrap_or_else(|| KalshiChannel::Custom(name.to_string()))
+    }
+}
+
+impl From<KalshiChannel> for String {
     fn from(val: KalshiChannel) -> Self {
-        val.as_str()
+        val.as_str().to_string()
     }
-}
\ No newline at end of file
+}
+
+impl std::fmt::Display for KalshiChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for KalshiChannel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for KalshiChannel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(KalshiChannel::from(name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        commands::{KalshiCommand, KalshiSubscribeCommandParams},
+        responses::KalshiWebsocketResponse,
+        KalshiChannel,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn subscribe_to_event_lifecycle_serializes_channel_name() {
+        let cmd = KalshiCommand::Subscribe {
+            id: 1,
+            params: KalshiSubscribeCommandParams {
+                channels: vec![KalshiChannel::EventLifecycle],
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&cmd).unwrap(),
+            json!({
+                "cmd": "subscribe",
+                "id": 1,
+                "params": { "channels": ["event_lifecycle"] }
+            })
+        );
+    }
+
+    #[test]
+    fn event_lifecycle_frame_decodes() {
+        let frame = r#"{
+            "type": "event_lifecycle",
+            "sid": 5,
+            "msg": {
+                "event_ticker": "KXFED-25DEC",
+                "title": "Fed decision in December?",
+                "subtitle": "Dec 10, 2025",
+                "collateral_return_type": "MECNET",
+                "series_ticker": "KXFED",
+                "strike_date": 1765393200
+            }
+        }"#;
+        let KalshiWebsocketResponse::EventLifecycle { sid, msg } =
+            KalshiWebsocketResponse::from_frame(frame).unwrap()
+        else {
+            panic!("expected an event_lifecycle message");
+        };
+        assert_eq!(sid, 5);
+        assert_eq!(msg.event_ticker, "KXFED-25DEC");
+        assert_eq!(msg.series_ticker, "KXFED");
+        assert_eq!(msg.collateral_return_type, "MECNET");
+        assert_eq!(msg.strike_date, Some(1765393200));
+        assert_eq!(msg.strike_period, None);
+    }
+}
@@ -0,0 +1,140 @@
+use super::{
+    client::KalshiWebsocketClient,
+    responses::{KalshiMarketPositionMessage, KalshiWebsocketResponse},
+};
+use crate::{paginate, Kalshi, KalshiError, MarketPosition};
+use futures_util::{StreamExt, TryStreamExt};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tokio::task::JoinHandle;
+
+/// The current position in one market, as tracked by a [`PositionCache`].
+///
+/// All amounts are in cents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionState {
+    pub market_ticker: String,
+    /// Number of contracts held; negative for a NO position.
+    pub position: i32,
+    /// Cost of the open position.
+    ///
+    /// `None` until the websocket reports the market: the REST positions endpoint only
+    /// returns the market exposure and the total traded, neither of which is the cost.
+    pub position_cost: Option<i64>,
+    pub realized_pnl: i64,
+    pub fees_paid: i64,
+}
+
+impl From<&KalshiMarketPositionMessage> for PositionState {
+    fn from(msg: &KalshiMarketPositionMessage) -> Self {
+        PositionState {
+            market_ticker: msg.market_ticker.clone(),
+            position: msg.position,
+            position_cost: Some(msg.position_cost),
+            realized_pnl: msg.realized_pnl,
+            fees_paid: msg.fees_paid,
+        }
+    }
+}
+
+impl From<&MarketPosition> for PositionState {
+    fn from(pos: &MarketPosition) -> Self {
+        PositionState {
+            market_ticker: pos.ticker.clone(),
+            position: pos.position,
+            position_cost: None,
+            realized_pnl: pos.realized_pnl,
+            fees_paid: pos.fees_paid,
+        }
+    }
+}
+
+/// Per-market positions kept up to date from the `market_positions` websocket channel.
+///
+/// Clones share the same state, so one task can feed the cache while any number of
+/// others read from it.
+///
+/// # Example
+/// ```
+/// # async fn example(k: &mut kalshi::Kalshi) -> Result<(), Box<dyn std::error::Error>> {
+/// use kalshi::{KalshiChannel, commands::KalshiSubscribeCommandParams, positions::PositionCache};
+///
+/// let mut ws = k.connect_ws().await?;
+/// let positions = PositionCache::new();
+/// let _feed = positions.spawn(&ws);
+/// ws.subscribe(KalshiSubscribeCommandParams {
+///     channels: vec![KalshiChannel::MarketPositions],
+///     ..Default::default()
+/// })
+/// .await?;
+/// positions.seed(k).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PositionCache {
+    positions: Arc<RwLock<HashMap<String, PositionState>>>,
+}
+
+impl PositionCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads every current position over REST.
+    ///
+    /// Markets already updated from the websocket keep their newer state, so seeding
+    /// after the feed has started is safe.
+    pub async fn seed(&self, kalshi: &Kalshi) -> Result<(), KalshiError> {
+        let positions: Vec<MarketPosition> = paginate(None, |cursor| async move {
+            let resp = kalshi.get_user_positions(None, cursor, None, None).await?;
+            Ok((resp.market_positions, resp.cursor.filter(|c| !c.is_empty())))
+        })
+        .try_collect()
+        .await?;
+
+        let mut cache = self.positions.write().unwrap();
+        for pos in &positions {
+            cache
+                .entry(pos.ticker.clone())
+                .or_insert_with(|| PositionState::from(pos));
+        }
+        Ok(())
+    }
+
+    /// Applies a single `market_positions` update.
+    pub fn apply(&self, msg: &KalshiMarketPositionMessage) {
+        self.positions
+            .write()
+            .unwrap()
+            .insert(msg.market_ticker.clone(), PositionState::from(msg));
+    }
+
+    /// Spawns a task that applies every position update received by `client`.
+    ///
+    /// The task ends once the websocket client shuts down.
+    pub fn spawn(&self, client: &KalshiWebsocketClient) -> JoinHandle<()> {
+        let cache = self.clone();
+        let mut messages = client.stream();
+        tokio::spawn(async move {
+            while let Some(res) = messages.next().await {
+                if let Ok(KalshiWebsocketResponse::MarketPosition { msg, .. }) = res {
+                    cache.apply(&msg);
+                }
+            }
+        })
+    }
+
+    /// Returns the position in a market, if any has been seen.
+    pub fn get(&self, market_ticker: &str) -> Option<PositionState> {
+        self.positions.read().unwrap().get(market_ticker).cloned()
+    }
+
+    /// Returns every tracked position.
+    pub fn all(&self) -> Vec<PositionState> {
+        self.positions.read().unwrap().values().cloned().collect()
+    }
+}
@@ -0,0 +1,97 @@
+use super::{
+    client::{KalshiWebsocketError, KalshiWebsocketStream},
+    responses::KalshiWebsocketResponse,
+};
+use chrono::{DateTime, Utc};
+use futures_util::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+
+/// A websocket text frame as received, with the time it arrived.
+///
+/// Frames can be captured with [`on_raw_message`](super::client::KalshiWebsocketClient::on_raw_message)
+/// and stored in any format serde supports, then fed back with
+/// [`KalshiWebsocketStream::replay`].
+///
+/// # Example
+/// ```
+/// # fn example(ws: &kalshi::client::KalshiWebsocketClient) {
+/// use kalshi::{client::FrameDirection, replay::RecordedFrame};
+/// use std::sync::{Arc, Mutex};
+///
+/// let recorded = Arc::new(Mutex::new(Vec::new()));
+/// let sink = Arc::clone(&recorded);
+/// ws.on_raw_message(move |direction, text| {
+///     if direction == FrameDirection::Inbound {
+///         sink.lock().unwrap().push(RecordedFrame::now(text));
+///     }
+/// });
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    pub received_at: DateTime<Utc>,
+    pub text: String,
+}
+
+impl RecordedFrame {
+    /// Records `text` as received right now.
+    pub fn now(text: impl Into<String>) -> Self {
+        RecordedFrame {
+            received_at: Utc::now(),
+            text: text.into(),
+        }
+    }
+}
+
+/// How fast [`KalshiWebsocketStream::replay`] delivers recorded frames.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReplayPacing {
+    /// Every frame is delivered as soon as it is polled.
+    #[default]
+    Unpaced,
+    /// Frames are spaced out as they were originally received.
+    Original,
+    /// Like `Original`, but this many times faster.
+    Speed(f64),
+}
+
+impl KalshiWebsocketStream {
+    /// Replays recorded frames through the same decoding as a live connection.
+    ///
+    /// The result is the same stream type as [`KalshiWebsocketClient::stream`](super::client::KalshiWebsocketClient::stream),
+    /// so consumers can be developed and debugged offline against the real message types.
+    /// Frames that fail to decode yield a
+    /// [`SerializationError`](KalshiWebsocketError::SerializationError) and replay continues.
+    ///
+    /// Frames are replayed verbatim: sids are not remapped and sequence gaps are not
+    /// resynced, since there is no connection to resubscribe on.
+    pub fn replay<I>(frames: I, pacing: ReplayPacing) -> Self
+    where
+        I: IntoIterator<Item = RecordedFrame>,
+        I::IntoIter: Send + 'static,
+    {
+        let speed = match pacing {
+            ReplayPacing::Unpaced => None,
+            ReplayPacing::Original => Some(1.0),
+            ReplayPacing::Speed(speed) if speed > 0.0 => Some(speed),
+            ReplayPacing::Speed(_) => None,
+        };
+        let frames = frames.into_iter();
+        let inner = stream::unfold(
+            (frames, None::<DateTime<Utc>>),
+            move |(mut frames, previous)| async move {
+                let frame = frames.next()?;
+                if let (Some(speed), Some(previous)) = (speed, previous) {
+                    if let Ok(gap) = (frame.received_at - previous).to_std() {
+                        sleep(gap.div_f64(speed)).await;
+                    }
+                }
+                let item = KalshiWebsocketResponse::from_frame(&frame.text)
+                    .map_err(|e| KalshiWebsocketError::SerializationError(e.to_string()).into());
+                Some((item, (frames, Some(frame.received_at))))
+            },
+        );
+        KalshiWebsocketStream::from_boxed(inner.boxed())
+    }
+}
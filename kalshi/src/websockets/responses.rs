@@ -1,5 +1,8 @@
+use super::{client::KalshiWebsocketError, KalshiChannel};
+use crate::{Orderbook, PriceLevel, PriceLevelDollars, PriceLevelDollarsFp, Side, Trade};
+use chrono::{SecondsFormat, TimeZone, Utc};
 use serde::Deserialize;
-use super::KalshiChannel;
+use std::time::Duration;
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(tag = "type")]
@@ -18,20 +21,11 @@ pub enum KalshiWebsocketResponse {
         msg: KalshiOrderbookDeltaMessage,
     },
     /// Market ticker information.
-    Ticker {
-        sid: u32,
-        msg: KalshiTickerMessage,
-    },
+    Ticker { sid: u32, msg: KalshiTickerMessage },
     /// Public trade notification.
-    Trade {
-        sid: u32,
-        msg: KalshiTradeMessage,
-    },
+    Trade { sid: u32, msg: KalshiTradeMessage },
     /// Private fill notification for the authenticated user.
-    Fill {
-        sid: u32,
-        msg: KalshiFillMessage,
-    },
+    Fill { sid: u32, msg: KalshiFillMessage },
     /// Market lifecycle event (v2).
     MarketLifecycleV2 {
         sid: u32,
@@ -94,11 +88,7 @@ pub enum KalshiWebsocketResponse {
         msg: KalshiSubscribedMessage,
     },
     /// Confirmation that an unsubscription was successful.
-    Unsubscribed {
-        id: Option<u32>,
-        sid: u32,
-        seq: u32,
-    },
+    Unsubscribed { id: Option<u32>, sid: u32, seq: u32 },
     /// Successful update operation or command response.
     Ok {
         id: Option<u32>,
@@ -111,6 +101,212 @@ pub enum KalshiWebsocketResponse {
         id: Option<u32>,
         msg: KalshiErrorMessage,
     },
+    /// Generated by the client, never sent by Kalshi: an orderbook subscription skipped
+    /// a sequence number and was resubscribed.
+    ///
+    /// Deltas received between the gap and this notification were dropped. The
+    /// `OrderbookSnapshot` that immediately follows replaces any locally maintained book.
+    #[serde(skip)]
+    Resynced {
+        sid: u32,
+        market_tickers: Vec<String>,
+    },
+    /// A message whose `type` this crate does not know yet, kept as raw JSON.
+    ///
+    /// Lets consumers log or handle new message types instead of seeing a
+    /// deserialization error.
+    #[serde(skip)]
+    Unknown { raw: serde_json::Value },
+    /// Generated by the client, never sent by Kalshi: a step of reconnecting after the
+    /// connection dropped, following the client's [`ReconnectPolicy`](super::client::ReconnectPolicy).
+    #[serde(skip)]
+    Reconnect(ReconnectEvent),
+    /// Generated by the client, never sent by Kalshi: a sequenced subscription skipped
+    /// or repeated a sequence number, so messages were lost or reordered.
+    ///
+    /// Sent ahead of the message that revealed it. An orderbook subscription is resynced
+    /// right away and reports [`Resynced`](KalshiWebsocketResponse::Resynced) once its
+    /// new snapshot arrives.
+    #[serde(skip)]
+    SequenceAnomaly {
+        sid: u32,
+        channel: KalshiChannel,
+        expected: u32,
+        received: u32,
+    },
+    /// Generated by the client, never sent by Kalshi: a subscription received no messages
+    /// for longer than its watchdog timeout.
+    ///
+    /// `resubscribed` tells whether the client already resubscribed it. Otherwise the
+    /// notification is not repeated until the subscription has delivered a message again.
+    #[serde(skip)]
+    Stale {
+        sid: u32,
+        channel: KalshiChannel,
+        market_tickers: Vec<String>,
+        idle: Duration,
+        resubscribed: bool,
+    },
+}
+
+/// A step of the reconnect lifecycle, delivered as
+/// [`KalshiWebsocketResponse::Reconnect`].
+#[derive(Debug, Clone)]
+pub enum ReconnectEvent {
+    /// The connection dropped and reconnect attempts follow.
+    Disconnected,
+    /// Waiting `delay` before attempt number `attempt` of this outage.
+    Attempting { attempt: u32, delay: Duration },
+    /// Attempt number `attempt` failed.
+    AttemptFailed {
+        attempt: u32,
+        error: KalshiWebsocketError,
+    },
+    /// Connected again on attempt number `attempts`. Subscriptions are being restored.
+    Reconnected { attempts: u32 },
+    /// No attempts are left and the client has closed.
+    GaveUp { attempts: u32 },
+}
+
+/// Every `type` tag Kalshi sends that decodes into a named variant.
+const KNOWN_MESSAGE_TYPES: [&str; 20] = [
+    "orderbook_snapshot",
+    "orderbook_delta",
+    "ticker",
+    "trade",
+    "fill",
+    "market_lifecycle_v2",
+    "event_lifecycle",
+    "multivariate_lookup",
+    "market_position",
+    "order_group_updates",
+    "user_order",
+    "rfq_created",
+    "rfq_deleted",
+    "quote_created",
+    "quote_accepted",
+    "quote_executed",
+    "subscribed",
+    "unsubscribed",
+    "ok",
+    "error",
+];
+
+impl KalshiWebsocketResponse {
+    /// Decodes a text frame, returning [`Unknown`](KalshiWebsocketResponse::Unknown) for
+    /// valid messages of an unrecognised type.
+    ///
+    /// Messages of a known type that fail to decode are still errors.
+    pub(crate) fn from_frame(text: &str) -> Result<Self, serde_json::Error> {
+        let err = match serde_json::from_str(text) {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+        let Ok(raw) = serde_json::from_str::<serde_json::Value>(text) else {
+            return Err(err);
+        };
+        match raw.get("type").and_then(|t| t.as_str()) {
+            Some(t) if !KNOWN_MESSAGE_TYPES.contains(&t) => {
+                Ok(KalshiWebsocketResponse::Unknown { raw })
+            }
+            _ => Err(err),
+        }
+    }
+
+    /// Returns the `type` tag of the message as Kalshi sends it.
+    pub fn message_type(&self) -> &'static str {
+        match self {
+            KalshiWebsocketResponse::OrderbookSnapshot { .. } => "orderbook_snapshot",
+            KalshiWebsocketResponse::OrderbookDelta { .. } => "orderbook_delta",
+            KalshiWebsocketResponse::Ticker { .. } => "ticker",
+            KalshiWebsocketResponse::Trade { .. } => "trade",
+            KalshiWebsocketResponse::Fill { .. } => "fill",
+            KalshiWebsocketResponse::MarketLifecycleV2 { .. } => "market_lifecycle_v2",
+            KalshiWebsocketResponse::EventLifecycle { .. } => "event_lifecycle",
+            KalshiWebsocketResponse::MultivariateLookup { .. } => "multivariate_lookup",
+            KalshiWebsocketResponse::MarketPosition { .. } => "market_position",
+            KalshiWebsocketResponse::OrderGroupUpdates { .. } => "order_group_updates",
+            KalshiWebsocketResponse::UserOrder { .. } => "user_order",
+            KalshiWebsocketResponse::RfqCreated { .. } => "rfq_created",
+            KalshiWebsocketResponse::RfqDeleted { .. } => "rfq_deleted",
+            KalshiWebsocketResponse::QuoteCreated { .. } => "quote_created",
+            KalshiWebsocketResponse::QuoteAccepted { .. } => "quote_accepted",
+            KalshiWebsocketResponse::QuoteExecuted { .. } => "quote_executed",
+            KalshiWebsocketResponse::Subscribed { .. } => "subscribed",
+            KalshiWebsocketResponse::Unsubscribed { .. } => "unsubscribed",
+            KalshiWebsocketResponse::Ok { .. } => "ok",
+            KalshiWebsocketResponse::Error { .. } => "error",
+            KalshiWebsocketResponse::Resynced { .. } => "resynced",
+            KalshiWebsocketResponse::Unknown { .. } => "unknown",
+            KalshiWebsocketResponse::Stale { .. } => "stale",
+            KalshiWebsocketResponse::Reconnect(_) => "reconnect",
+            KalshiWebsocketResponse::SequenceAnomaly { .. } => "sequence_anomaly",
+        }
+    }
+
+    /// Returns the subscription id the message belongs to, if it carries one.
+    pub fn sid(&self) -> Option<u32> {
+        match self {
+            KalshiWebsocketResponse::OrderbookSnapshot { sid, .. }
+            | KalshiWebsocketResponse::OrderbookDelta { sid, .. }
+            | KalshiWebsocketResponse::Ticker { sid, .. }
+            | KalshiWebsocketResponse::Trade { sid, .. }
+            | KalshiWebsocketResponse::Fill { sid, .. }
+            | KalshiWebsocketResponse::MarketLifecycleV2 { sid, .. }
+            | KalshiWebsocketResponse::EventLifecycle { sid, .. }
+            | KalshiWebsocketResponse::MultivariateLookup { sid, .. }
+            | KalshiWebsocketResponse::MarketPosition { sid, .. }
+            | KalshiWebsocketResponse::OrderGroupUpdates { sid, .. }
+            | KalshiWebsocketResponse::UserOrder { sid, .. }
+            | KalshiWebsocketResponse::RfqCreated { sid, .. }
+            | KalshiWebsocketResponse::RfqDeleted { sid, .. }
+            | KalshiWebsocketResponse::QuoteCreated { sid, .. }
+            | KalshiWebsocketResponse::QuoteAccepted { sid, .. }
+            | KalshiWebsocketResponse::QuoteExecuted { sid, .. }
+            | KalshiWebsocketResponse::Unsubscribed { sid, .. }
+            | KalshiWebsocketResponse::Resynced { sid, .. }
+            | KalshiWebsocketResponse::Stale { sid, .. }
+            | KalshiWebsocketResponse::SequenceAnomaly { sid, .. } => Some(*sid),
+            KalshiWebsocketResponse::Subscribed { msg, .. } => Some(msg.sid),
+            KalshiWebsocketResponse::Ok { sid, .. } => *sid,
+            KalshiWebsocketResponse::Error { .. } | KalshiWebsocketResponse::Reconnect(_) => None,
+            KalshiWebsocketResponse::Unknown { raw } => raw
+                .get("sid")
+                .and_then(|sid| sid.as_u64())
+                .and_then(|sid| u32::try_from(sid).ok()),
+        }
+    }
+
+    /// Mutable access to the subscription id, used to remap server sids to client sids.
+    pub(crate) fn sid_mut(&mut self) -> Option<&mut u32> {
+        match self {
+            KalshiWebsocketResponse::OrderbookSnapshot { sid, .. }
+            | KalshiWebsocketResponse::OrderbookDelta { sid, .. }
+            | KalshiWebsocketResponse::Ticker { sid, .. }
+            | KalshiWebsocketResponse::Trade { sid, .. }
+            | KalshiWebsocketResponse::Fill { sid, .. }
+            | KalshiWebsocketResponse::MarketLifecycleV2 { sid, .. }
+            | KalshiWebsocketResponse::EventLifecycle { sid, .. }
+            | KalshiWebsocketResponse::MultivariateLookup { sid, .. }
+            | KalshiWebsocketResponse::MarketPosition { sid, .. }
+            | KalshiWebsocketResponse::OrderGroupUpdates { sid, .. }
+            | KalshiWebsocketResponse::UserOrder { sid, .. }
+            | KalshiWebsocketResponse::RfqCreated { sid, .. }
+            | KalshiWebsocketResponse::RfqDeleted { sid, .. }
+            | KalshiWebsocketResponse::QuoteCreated { sid, .. }
+            | KalshiWebsocketResponse::QuoteAccepted { sid, .. }
+            | KalshiWebsocketResponse::QuoteExecuted { sid, .. }
+            | KalshiWebsocketResponse::Unsubscribed { sid, .. }
+            | KalshiWebsocketResponse::Resynced { sid, .. }
+            | KalshiWebsocketResponse::Stale { sid, .. }
+            | KalshiWebsocketResponse::SequenceAnomaly { sid, .. } => Some(sid),
+            KalshiWebsocketResponse::Subscribed { msg, .. } => Some(&mut msg.sid),
+            KalshiWebsocketResponse::Ok { sid, .. } => sid.as_mut(),
+            KalshiWebsocketResponse::Error { .. }
+            | KalshiWebsocketResponse::Unknown { .. }
+            | KalshiWebsocketResponse::Reconnect(_) => None,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -145,12 +341,25 @@ pub struct KalshiErrorMessage {
 pub struct KalshiOrderbookSnapshotMessage {
     pub market_ticker: String,
     pub market_id: String,
-    pub yes: Option<Vec<(u32, u32)>>,
-    pub yes_dollars: Option<Vec<(String, u32)>>,
-    pub yes_dollars_fp: Option<Vec<(String, String)>>,
-    pub no: Option<Vec<(u32, u32)>>,
-    pub no_dollars: Option<Vec<(String, u32)>>,
-    pub no_dollars_fp: Option<Vec<(String, String)>>,
+    pub yes: Option<Vec<PriceLevel>>,
+    pub yes_dollars: Option<Vec<PriceLevelDollars>>,
+    pub yes_dollars_fp: Option<Vec<PriceLevelDollarsFp>>,
+    pub no: Option<Vec<PriceLevel>>,
+    pub no_dollars: Option<Vec<PriceLevelDollars>>,
+    pub no_dollars_fp: Option<Vec<PriceLevelDollarsFp>>,
+}
+
+impl From<KalshiOrderbookSnapshotMessage> for Orderbook {
+    fn from(msg: KalshiOrderbookSnapshotMessage) -> Self {
+        Orderbook {
+            yes: msg.yes,
+            yes_dollars: msg.yes_dollars,
+            yes_dollars_fp: msg.yes_dollars_fp,
+            no: msg.no,
+            no_dollars: msg.no_dollars,
+            no_dollars_fp: msg.no_dollars_fp,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -201,6 +410,29 @@ pub struct KalshiTradeMessage {
     pub ts: i64,
 }
 
+impl From<KalshiTradeMessage> for Trade {
+    /// Converts a streamed trade into the type returned by
+    /// [`Kalshi::get_trades`](crate::Kalshi::get_trades). `created_time` is rendered
+    /// from `ts` as RFC 3339, like the REST API sends it.
+    fn from(msg: KalshiTradeMessage) -> Self {
+        Trade {
+            trade_id: msg.trade_id,
+            ticker: msg.market_ticker,
+            taker_side: msg.taker_side.into(),
+            count: msg.count,
+            count_fp: Some(msg.count_fp),
+            yes_price: msg.yes_price,
+            yes_price_dollars: Some(msg.yes_price_dollars),
+            no_price: msg.no_price,
+            no_price_dollars: Some(msg.no_price_dollars),
+            created_time: Utc
+                .timestamp_opt(msg.ts, 0)
+                .single()
+                .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct KalshiFillMessage {
     pub trade_id: String,
@@ -423,9 +655,18 @@ pub enum KalshiSide {
     No,
 }
 
+impl From<KalshiSide> for Side {
+    fn from(side: KalshiSide) -> Self {
+        match side {
+            KalshiSide::Yes => Side::Yes,
+            KalshiSide::No => Side::No,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum KalshiAction {
     Buy,
     Sell,
-}
\ No newline at end of file
+}
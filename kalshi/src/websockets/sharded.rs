@@ -0,0 +1,180 @@
+use super::{
+    client::{KalshiWebsocketClient, KalshiWebsocketStream},
+    commands::KalshiSubscribeCommandParams,
+    responses::KalshiErrorMessage,
+    KalshiChannel,
+};
+use crate::{Kalshi, KalshiError};
+use futures_util::{
+    stream::{select_all, SelectAll},
+    Stream, StreamExt,
+};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    error::Error,
+    hash::{Hash, Hasher},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// How a [`ShardedWsClient`] assigns markets to connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardStrategy {
+    /// Each new market goes to the next connection in turn.
+    RoundRobin,
+    /// A market always goes to the connection picked by hashing its ticker.
+    Hash,
+}
+
+struct Shard {
+    client: KalshiWebsocketClient,
+    /// Subscriptions made on this connection, kept so they can move on rebalance.
+    subscriptions: Vec<(Vec<KalshiChannel>, Vec<String>)>,
+}
+
+/// Spreads market subscriptions over several websocket connections and merges their
+/// messages into one stream.
+///
+/// Sids are assigned by each connection independently, so they are only unique within
+/// a shard. When a connection closes, its stream yields a `ConnectionClosed` error;
+/// call [`rebalance`](ShardedWsClient::rebalance) to replace it and move its markets.
+pub struct ShardedWsClient {
+    shards: Vec<Shard>,
+    strategy: ShardStrategy,
+    next_shard: usize,
+    messages: SelectAll<KalshiWebsocketStream>,
+}
+
+impl ShardedWsClient {
+    /// Opens `shard_count` connections.
+    pub async fn connect(
+        kalshi: &mut Kalshi,
+        shard_count: usize,
+        strategy: ShardStrategy,
+    ) -> Result<Self, Box<dyn Error>> {
+        if shard_count == 0 {
+            return Err(KalshiError::UserInputError(
+                "A sharded websocket client needs at least one shard".to_string(),
+            )
+            .into());
+        }
+        let mut shards = Vec::with_capacity(shard_count);
+        for _ in 0..shard_count {
+            shards.push(Shard {
+                client: KalshiWebsocketClient::connect(kalshi).await?,
+                subscriptions: Vec::new(),
+            });
+        }
+        let messages = select_all(shards.iter().map(|shard| shard.client.stream()));
+        Ok(ShardedWsClient {
+            shards,
+            strategy,
+            next_shard: 0,
+            messages,
+        })
+    }
+
+    /// Returns the number of connections.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the shard each subscribed market is assigned to.
+    pub fn assignments(&self) -> HashMap<String, usize> {
+        let mut assignments = HashMap::new();
+        for (index, shard) in self.shards.iter().enumerate() {
+            for (_, tickers) in &shard.subscriptions {
+                for ticker in tickers {
+                    assignments.insert(ticker.clone(), index);
+                }
+            }
+        }
+        assignments
+    }
+
+    fn pick_shard(&mut self, ticker: &str) -> usize {
+        match self.strategy {
+            ShardStrategy::RoundRobin => {
+                let index = self.next_shard % self.shards.len();
+                self.next_shard = self.next_shard.wrapping_add(1);
+                index
+            }
+            ShardStrategy::Hash => {
+                let mut hasher = DefaultHasher::new();
+                ticker.hash(&mut hasher);
+                (hasher.finish() % self.shards.len() as u64) as usize
+            }
+        }
+    }
+
+    /// Subscribes to `channels` for every market in `tickers`, spreading the markets over
+    /// the connections.
+    ///
+    /// Resolves once every connection involved has acknowledged its part, with the
+    /// markets Kalshi rejected.
+    pub async fn subscribe_markets(
+        &mut self,
+        channels: Vec<KalshiChannel>,
+        tickers: Vec<String>,
+    ) -> Result<Vec<(String, KalshiErrorMessage)>, Box<dyn Error>> {
+        let mut rejected = Vec::new();
+        let mut by_shard: HashMap<usize, Vec<String>> = HashMap::new();
+        for ticker in tickers {
+            let index = self.pick_shard(&ticker);
+            by_shard.entry(index).or_default().push(ticker);
+        }
+        for (index, mut tickers) in by_shard {
+            let shard = &mut self.shards[index];
+            let outcome = shard
+                .client
+                .subscribe(KalshiSubscribeCommandParams {
+                    channels: channels.clone(),
+                    market_tickers: Some(tickers.clone()),
+                    ..Default::default()
+                })
+                .await?;
+            tickers.retain(|t| !outcome.rejected.iter().any(|(r, _)| r == t));
+            shard.subscriptions.push((channels.clone(), tickers));
+            rejected.extend(outcome.rejected);
+        }
+        Ok(rejected)
+    }
+
+    /// Replaces every closed connection and redistributes its markets.
+    ///
+    /// Returns the number of connections that were replaced.
+    pub async fn rebalance(&mut self, kalshi: &mut Kalshi) -> Result<usize, Box<dyn Error>> {
+        let mut orphaned = Vec::new();
+        let mut replaced = 0;
+        for shard in self
+            .shards
+            .iter_mut()
+            .filter(|shard| shard.client.is_closed())
+        {
+            let client = KalshiWebsocketClient::connect(kalshi).await?;
+            self.messages.push(client.stream());
+            shard.client = client;
+            orphaned.append(&mut shard.subscriptions);
+            replaced += 1;
+        }
+        for (channels, tickers) in orphaned {
+            self.subscribe_markets(channels, tickers).await?;
+        }
+        Ok(replaced)
+    }
+
+    /// Closes every connection.
+    pub async fn close(self) {
+        for shard in self.shards {
+            shard.client.close().await;
+        }
+    }
+}
+
+impl Stream for ShardedWsClient {
+    type Item = <KalshiWebsocketStream as Stream>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_next_unpin(cx)
+    }
+}